@@ -0,0 +1,196 @@
+//! Count heap allocations of the wrapper's reused per-game buffers against
+//! allocating fresh buffers per call.
+//!
+//! Run with `cargo bench --bench allocations`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    ptr::null,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use mirabel::{error::*, game::*, game_init::GameInit, testing::Countdown, *};
+
+/// Counts allocations and reallocations but not deallocations.
+struct Counting;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const CALLS: u64 = 1_000_000;
+
+/// Return the number of allocations made by `f`.
+fn allocations_during(f: impl FnOnce()) -> u64 {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn methods_of<G: GameMethods>(name: &str) -> sys::game_methods {
+    create_game_methods::<G>(
+        Metadata::builder()
+            .game_name(name)
+            .variant_name("Standard")
+            .impl_name("mirabel_rs")
+            .build(),
+    )
+}
+
+/// Create a game with default options and state.
+///
+/// # Safety
+/// The game must be destroyed before `methods` are dropped.
+unsafe fn create(methods: &sys::game_methods) -> sys::game {
+    let mut game = sys::game {
+        methods,
+        ..Default::default()
+    };
+    let mut init = sys::game_init {
+        source_type: sys::GAME_INIT_SOURCE_TYPE_E_GAME_INIT_SOURCE_TYPE_DEFAULT,
+        ..Default::default()
+    };
+    methods.create.unwrap()(&mut game, &mut init);
+    game
+}
+
+/// Move counts which cycle between queries, like in different positions.
+const MOVE_COUNTS: [u64; 5] = [5, 200, 17, 1000, 60];
+
+/// Game whose number of moves changes with every query.
+#[derive(Clone, PartialEq, Eq)]
+struct Varying {
+    queries: usize,
+}
+
+impl SimpleGame for Varying {
+    fn create(_: &GameInit) -> Result<Self> {
+        Ok(Self { queries: 0 })
+    }
+    fn player_count(&mut self) -> Result<u8> {
+        Ok(1)
+    }
+    fn import_state(&mut self, _: Option<&str>) -> Result<()> {
+        Ok(())
+    }
+    fn export_state(&mut self, _: player_id, _: &mut ValidCString) -> Result<()> {
+        Ok(())
+    }
+    fn to_move(&mut self) -> Result<Option<player_id>> {
+        Ok(Some(1))
+    }
+    fn get_concrete_moves(&mut self, _: player_id, moves: &mut Vec<MoveCode>) -> Result<()> {
+        let count = MOVE_COUNTS[self.queries % MOVE_COUNTS.len()];
+        self.queries += 1;
+        moves.extend_codes(1..=count);
+        Ok(())
+    }
+    fn get_move_data(&mut self, _: player_id, _: &str) -> Result<move_code> {
+        unimplemented!()
+    }
+    fn get_move_str(&mut self, _: player_id, _: move_code, _: &mut ValidCString) -> Result<()> {
+        unimplemented!()
+    }
+    fn make_move(&mut self, _: player_id, _: move_code) -> Result<()> {
+        Ok(())
+    }
+    fn get_results(&mut self, _: &mut Vec<player_id>) -> Result<()> {
+        Ok(())
+    }
+    fn is_legal_move(&mut self, _: player_id, _: move_code) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A million `players_to_move` calls, e.g., by a search querying every node.
+fn players_to_move() {
+    let fresh = allocations_during(|| {
+        let mut game = Countdown(10);
+        for _ in 0..CALLS {
+            let mut players = vec![];
+            GameMethods::players_to_move(&mut game, &mut players).unwrap();
+            black_box(players);
+        }
+    });
+
+    let methods = methods_of::<Countdown>("Countdown");
+    let reused = unsafe {
+        let mut game = create(&methods);
+        let players_to_move = methods.players_to_move.unwrap();
+        let (mut count, mut players) = (0, null());
+        // The first call sizes the buffer.
+        players_to_move(&mut game, &mut count, &mut players);
+        let reused = allocations_during(|| {
+            for _ in 0..CALLS {
+                players_to_move(&mut game, &mut count, &mut players);
+                black_box((count, players));
+            }
+        });
+        methods.destroy.unwrap()(&mut game);
+        reused
+    };
+
+    println!("players_to_move, {CALLS} calls:");
+    println!("  fresh Vec per call:  {fresh:>8} allocations");
+    println!("  reused Aux buffer:   {reused:>8} allocations");
+    assert_eq!(0, reused);
+}
+
+/// A million `get_concrete_moves` calls with mixed move counts.
+fn mixed_move_counts() {
+    let fresh = allocations_during(|| {
+        let mut game = Varying { queries: 0 };
+        for _ in 0..CALLS {
+            let mut moves = vec![];
+            GameMethods::get_concrete_moves(&mut game, 1, &mut moves).unwrap();
+            black_box(moves);
+        }
+    });
+
+    let methods = methods_of::<Varying>("Varying");
+    let reused = unsafe {
+        let mut game = create(&methods);
+        let get_concrete_moves = methods.get_concrete_moves.unwrap();
+        let (mut count, mut moves) = (0, null());
+        // One round grows the buffer to the largest move count.
+        for _ in MOVE_COUNTS {
+            get_concrete_moves(&mut game, 1, &mut count, &mut moves);
+        }
+        let reused = allocations_during(|| {
+            for _ in 0..CALLS {
+                get_concrete_moves(&mut game, 1, &mut count, &mut moves);
+                black_box((count, moves));
+            }
+        });
+        methods.destroy.unwrap()(&mut game);
+        reused
+    };
+
+    println!("get_concrete_moves with {MOVE_COUNTS:?} moves, {CALLS} calls:");
+    println!("  fresh Vec per call:  {fresh:>8} allocations");
+    println!("  reused Aux buffer:   {reused:>8} allocations");
+    assert_eq!(0, reused);
+}
+
+fn main() {
+    players_to_move();
+    mixed_move_counts();
+}
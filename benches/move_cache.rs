@@ -0,0 +1,98 @@
+//! Compare repeated `get_concrete_moves` queries with and without
+//! [`GameFeatures::move_cache`].
+//!
+//! Run with `cargo bench --bench move_cache`.
+
+use std::{hint::black_box, ptr::null, time::Instant};
+
+use mirabel::{error::*, game::*, game_init::GameInit, *};
+
+/// Number of queries per position, e.g., by an engine before it decides.
+const QUERIES: u32 = 100_000;
+
+/// Game with a large branching factor whose moves are costly to generate.
+#[derive(Clone, PartialEq, Eq)]
+struct Wide;
+
+impl SimpleGame for Wide {
+    fn create(_: &GameInit) -> Result<Self> {
+        Ok(Self)
+    }
+    fn player_count(&mut self) -> Result<u8> {
+        Ok(1)
+    }
+    fn import_state(&mut self, _: Option<&str>) -> Result<()> {
+        Ok(())
+    }
+    fn export_state(&mut self, _: player_id, _: &mut ValidCString) -> Result<()> {
+        Ok(())
+    }
+    fn to_move(&mut self) -> Result<Option<player_id>> {
+        Ok(Some(1))
+    }
+    fn get_concrete_moves(&mut self, _: player_id, moves: &mut Vec<MoveCode>) -> Result<()> {
+        // Some legality filtering as in real move generators.
+        moves.extend_codes((0..2000).filter(|m| m % 3 != 0));
+        Ok(())
+    }
+    fn get_move_data(&mut self, _: player_id, _: &str) -> Result<move_code> {
+        unimplemented!()
+    }
+    fn get_move_str(&mut self, _: player_id, _: move_code, _: &mut ValidCString) -> Result<()> {
+        unimplemented!()
+    }
+    fn make_move(&mut self, _: player_id, _: move_code) -> Result<()> {
+        Ok(())
+    }
+    fn get_results(&mut self, _: &mut Vec<player_id>) -> Result<()> {
+        Ok(())
+    }
+    fn is_legal_move(&mut self, _: player_id, _: move_code) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Return the average duration of a query in nanoseconds.
+fn bench(move_cache: bool) -> f64 {
+    let methods = create_game_methods::<Wide>(
+        Metadata::builder()
+            .game_name("Wide")
+            .variant_name("Standard")
+            .impl_name("mirabel_rs")
+            .features(GameFeatures {
+                move_cache,
+                ..Default::default()
+            })
+            .build(),
+    );
+    let mut game = sys::game {
+        methods: &methods,
+        ..Default::default()
+    };
+    let mut init = sys::game_init {
+        source_type: sys::GAME_INIT_SOURCE_TYPE_E_GAME_INIT_SOURCE_TYPE_DEFAULT,
+        ..Default::default()
+    };
+    let get_concrete_moves = methods.get_concrete_moves.unwrap();
+    let (mut count, mut moves) = (0, null());
+    unsafe {
+        methods.create.unwrap()(&mut game, &mut init);
+        let start = Instant::now();
+        for _ in 0..QUERIES {
+            get_concrete_moves(&mut game, 1, &mut count, &mut moves);
+            black_box((count, moves));
+        }
+        let elapsed = start.elapsed();
+        methods.destroy.unwrap()(&mut game);
+        assert_eq!(1333, count);
+        elapsed.as_nanos() as f64 / f64::from(QUERIES)
+    }
+}
+
+fn main() {
+    let uncached = bench(false);
+    let cached = bench(true);
+    println!("get_concrete_moves without move_cache: {uncached:>10.1} ns/query");
+    println!("get_concrete_moves with move_cache:    {cached:>10.1} ns/query");
+    println!("speedup: {:.1}x", uncached / cached);
+}
@@ -38,6 +38,9 @@ pub(crate) fn bindings() {
             "SDL2/SDL_events.h",
             "SDL2/SDL_video.h",
             "SDL2/SDL_mouse.h",
+            "SDL2/SDL_keycode.h",
+            "SDL2/SDL_clipboard.h",
+            "SDL2/SDL_error.h",
         ]);
     }
 
@@ -64,6 +67,9 @@ pub(crate) fn bindings() {
     // Block variables which break because of https://github.com/rust-lang/rust-bindgen/issues/753
     let builder = builder.blocklist_item(regex::escape("LS_ERR"));
     let builder = builder.blocklist_item(regex::escape("MOVE_NONE"));
+    // Required for freeing strings allocated by SDL.
+    #[cfg(feature = "mirabel")]
+    let builder = builder.allowlist_function("SDL_free");
 
     let bindings = builder.generate().expect("unable to generate bindings");
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
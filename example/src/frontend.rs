@@ -9,7 +9,6 @@ use mirabel::{
         skia::{Color4f, Font, Paint, Point, Rect, TextBlob},
         *,
     },
-    game::semver,
     sdl_event::*,
     *,
 };
@@ -36,13 +35,14 @@ impl FrontendMethods for Frontend {
     type Options = bool;
 
     /// Creates an instance of the frontend.
-    fn create(_options: Option<&Self::Options>) -> Result<Self> {
+    fn create(_options: Option<&Self::Options>, _display: &frontend_display_data) -> Result<Self> {
+        check_gl_version()?;
         Ok(Self::default())
     }
 
     /// Displays the runtime options using _ImGui_.
     fn runtime_opts_display(&mut self, ctx: Context<Self>) -> Result<()> {
-        if !ctx.options.unwrap() {
+        if !ctx.options().unwrap() {
             return Ok(());
         }
 
@@ -58,7 +58,7 @@ impl FrontendMethods for Frontend {
         imgui::begin_disabled(self.checkbox);
         imgui::input_text(cstr("Text Input\0"), &mut self.text, 15);
         imgui::slider_scalar(cstr("Slider\0"), &mut self.slider, 0f32, 42f32);
-        imgui::input_scalar(cstr("Scalar Input\0"), &mut self.scalar);
+        imgui::input_scalar_clamped(cstr("Scalar Input\0"), &mut self.scalar, -100, 100);
         imgui::end_disabled();
 
         Ok(())
@@ -81,13 +81,12 @@ impl FrontendMethods for Frontend {
 
     /// Process _SDL_ events.
     fn process_input(&mut self, ctx: Context<Self>, event: SDLEventEnum) -> Result<()> {
-        let matrix = ctx.canvas.matrix().invert().expect("matrix not invertible");
         match event {
             SDLEventEnum::MouseMotion(event) => {
-                self.mouse_location = Some(matrix.map_point((event.x, event.y)));
+                self.mouse_location = ctx.map_input_point(event.x, event.y);
             }
             SDLEventEnum::MouseButtonUp(event) => {
-                self.click_location = Some(matrix.map_point((event.x, event.y)));
+                self.click_location = ctx.map_input_point(event.x, event.y);
             }
             _ => (),
         };
@@ -97,8 +96,12 @@ impl FrontendMethods for Frontend {
 
     /// Update the internal state.
     fn update(&mut self, ctx: Context<Self>) -> Result<()> {
+        if !ctx.pointer_inside {
+            self.highlight_area = None;
+            return Ok(());
+        }
         let Some(mouse) = self.mouse_location else {
-             return Ok(());
+            return Ok(());
         };
 
         let width = ctx.display_data.w;
@@ -115,10 +118,9 @@ impl FrontendMethods for Frontend {
     /// Render the background using _Skia_.
     fn render(&mut self, mut ctx: Context<Self>) -> Result<()> {
         let dd = ctx.display_data;
-        let matrix = &ctx.canvas.matrix();
-        let c = ctx.canvas.get().set_matrix(&matrix.into());
+        let background = ctx.display().background_color();
+        let c = ctx.canvas.begin_frame(background);
 
-        c.clear(Color4f::new(1., 1., 1., 1.));
         if let Some(area) = self.highlight_area {
             c.draw_rect(area, &Paint::new(Color4f::new(1., 0.8, 0.8, 1.), None));
         }
@@ -160,7 +162,7 @@ impl FrontendMethods for Frontend {
 
     /// Determine whether we are compatible to the game or not.
     fn is_game_compatible(game: GameInfo) -> CodeResult<()> {
-        if !game.game_name.eq_ignore_ascii_case("chess") {
+        if !game.name_matches("chess") {
             Ok(())
         } else {
             Err(ErrorCode::FeatureUnsupported)
@@ -198,17 +200,10 @@ impl Default for Frontend {
 
 /// Create the [`Metadata`] for this frontend.
 fn example_metadata() -> Metadata {
-    let mut features = frontend_feature_flags::default();
-    features.set_options(true);
-
     Metadata {
         frontend_name: cstr("Example\0"),
-        version: semver {
-            major: 0,
-            minor: 1,
-            patch: 0,
-        },
-        features,
+        version: semver_from_cargo!(),
+        features: FrontendFeatures { options: true }.into(),
     }
 }
 
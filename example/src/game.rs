@@ -1,6 +1,13 @@
 //! Example (misère) _Nim_ game for showing how to use the wrapper library.
 
-use mirabel::{error::*, game::*, game_init::GameInit, *};
+use mirabel::{
+    convert::move_code_to,
+    error::*,
+    game::*,
+    game_init::{GameInit, OptionsParser},
+    helpers::TwoPlayerZeroSum,
+    *,
+};
 
 use std::fmt::Write;
 
@@ -12,8 +19,36 @@ const DEFAULT_MAX_SUB: Counter = 3;
 /// This struct contains the game data.
 ///
 /// It acts as the `Self` for the surena API calls.
+///
+/// # Example
+/// ```
+/// # use example::game::Nim;
+/// # use mirabel::{game::GameMethods, game_init::GameInit};
+/// // Not overridden, so this creates a default game.
+/// assert_eq!(2, Nim::max_players().unwrap());
+///
+/// // Not overridden either, so this checks the players to move.
+/// let mut nim = Nim::create(&GameInit::Default).unwrap();
+/// assert!(!nim.is_terminal().unwrap());
+/// nim.import_state(Some("b 0")).unwrap();
+/// assert!(nim.is_terminal().unwrap());
+///
+/// // The provided copy_from() matches the former hand-written version.
+/// let copy_by_hand = |game: &mut Nim, other: &mut Nim| *game = *other;
+/// let init = GameInit::Standard {
+///     opts: Some("10 2"),
+///     legacy: None,
+///     state: Some("b 5"),
+/// };
+/// let mut other = Nim::create(&init).unwrap();
+/// let mut provided = Nim::create(&GameInit::Default).unwrap();
+/// provided.copy_from(&mut other).unwrap();
+/// let mut by_hand = Nim::create(&GameInit::Default).unwrap();
+/// copy_by_hand(&mut by_hand, &mut other);
+/// assert!(provided == by_hand);
+/// ```
 #[derive(Copy, Clone, PartialEq, Eq)]
-struct Nim {
+pub struct Nim {
     counter: Counter,
     max_sub: Counter,
     initial_counter: Counter,
@@ -32,42 +67,11 @@ impl Nim {
 
     fn from_options(opts: &str) -> Result<Self> {
         // eg. "21 3"
-        let mut split = opts.split_whitespace();
-
-        let counter = match split.next() {
-            None => {
-                // Remember to include a trailing NUL byte for static errors!
-                return Err(Error::new_static(
-                    ErrorCode::InvalidInput,
-                    "missing starting counter\0",
-                ));
-            }
-            Some(c) => c,
-        };
-        let counter = counter.parse().map_err(|e| {
-            // Errors can be nicely handled using new_dynamic and format!().
-            Error::new_dynamic(
-                ErrorCode::InvalidInput,
-                format!("counter parsing error: {e}"),
-            )
-        })?;
-
-        let max_sub = match split.next() {
-            None => {
-                return Err(Error::new_static(
-                    ErrorCode::InvalidInput,
-                    "missing maximum subtrahend\0",
-                ))
-            }
-            Some(s) => s,
-        };
-        let max_sub = max_sub.parse().map_err(|e| {
-            Error::new_dynamic(
-                ErrorCode::InvalidInput,
-                format!("subtrahend parsing error: {e}"),
-            )
-        })?;
+        let mut parser = OptionsParser::new(opts);
+        let counter = parser.next_parsed("starting counter")?;
+        let max_sub = parser.next_parsed("maximum subtrahend")?;
         if max_sub == 0 {
+            // Remember to include a trailing NUL byte for static errors!
             return Err(Error::new_static(
                 ErrorCode::InvalidOptions,
                 "maximum subtrahend is zero\0",
@@ -77,6 +81,29 @@ impl Nim {
         Ok(Nim::new(counter, max_sub))
     }
 
+    /// Inverse of [`GameMethods::serialize()`].
+    fn from_serialized(bytes: &[u8]) -> Result<Self> {
+        let &[c0, c1, m0, m1, i0, i1, turn] = bytes else {
+            return Err(Error::new_static(
+                ErrorCode::InvalidInput,
+                "serialized state has wrong length\0",
+            ));
+        };
+        let game = Self {
+            counter: Counter::from_le_bytes([c0, c1]),
+            max_sub: Counter::from_le_bytes([m0, m1]),
+            initial_counter: Counter::from_le_bytes([i0, i1]),
+            turn: turn != 0,
+        };
+        if game.max_sub == 0 || game.counter > game.initial_counter {
+            return Err(Error::new_static(
+                ErrorCode::InvalidState,
+                "serialized state is inconsistent\0",
+            ));
+        }
+        Ok(game)
+    }
+
     /// Importing the default options should reset the game state.
     fn reset(&mut self) {
         self.counter = self.initial_counter;
@@ -104,6 +131,12 @@ impl Default for Nim {
     }
 }
 
+impl TwoPlayerZeroSum for Nim {
+    fn winner(&mut self) -> Result<Option<player_id>> {
+        Ok((self.counter == 0).then(|| self.player_id()))
+    }
+}
+
 impl GameMethods for Nim {
     /// We need to specify whether we want to use move codes or big moves.
     type Move = MoveCode;
@@ -134,12 +167,7 @@ impl GameMethods for Nim {
                 g.import_state(*state)?;
                 g
             }
-            GameInit::Serialized(_) => {
-                return Err(Error::new_static(
-                    ErrorCode::FeatureUnsupported,
-                    "initialization via serialized state unsupported",
-                ))
-            }
+            GameInit::Serialized(bytes) => Self::from_serialized(bytes)?,
         })
     }
 
@@ -152,12 +180,12 @@ impl GameMethods for Nim {
         Ok(())
     }
 
-    /// Simply copy the data from `other` to `self`.
-    ///
-    /// The idea is to reuse eg., allocated buffers as much as possible.
-    fn copy_from(&mut self, other: &mut Self) -> Result<()> {
-        *self = *other;
-        Ok(())
+    // copy_from is provided using Clone::clone_from(). Override it to reuse
+    // eg., allocated buffers as much as possible.
+
+    /// The initial counter does not affect the remaining play.
+    fn game_eq(&self, other: &Self) -> bool {
+        self.counter == other.counter && self.max_sub == other.max_sub && self.turn == other.turn
     }
 
     fn player_count(&mut self) -> Result<u8> {
@@ -204,12 +232,7 @@ impl GameMethods for Nim {
                 ))
             }
         };
-        self.counter = counter.parse().map_err(|e| {
-            Error::new_dynamic(
-                ErrorCode::InvalidInput,
-                format!("counter parsing error: {e}"),
-            )
-        })?;
+        self.counter = counter.parse().context("counter parsing error")?;
 
         Ok(())
     }
@@ -220,33 +243,27 @@ impl GameMethods for Nim {
         Ok(())
     }
 
-    /// The players which are to move can be simply [`push()`](Vec::push())ed
-    /// into `players` as long as [`u8::MAX`] is not exceeded.
+    /// With exactly one player to move, [`GameMethods::to_move()`] is enough.
+    /// The default [`GameMethods::players_to_move()`] pushes this player.
     ///
-    /// Alternatively, players can be assembled in another array and then
-    /// copied:
-    /// ```ignore
-    /// let local = [1, 3, 4];
-    /// players.extend_from_slice(&local);
-    /// ```
-    fn players_to_move(&mut self, players: &mut Vec<player_id>) -> Result<()> {
-        if self.counter > 0 {
-            players.push(self.player_id());
-        }
-        Ok(())
+    /// Games with simultaneous moves instead override `players_to_move` and
+    /// [`push()`](Vec::push()) the players into `players` as long as
+    /// [`u8::MAX`] is not exceeded.
+    /// Use [`PlayerSink::try_push()`] if this might happen.
+    fn to_move(&mut self) -> Result<Option<player_id>> {
+        Ok((self.counter > 0).then(|| self.player_id()))
     }
 
     /// The available moves can be simply [`push()`](Vec::push())ed into
-    /// `moves`.
+    /// `moves` or appended using [`MoveSink`].
     /// The type of `moves` depends on [`Self::Move`].
+    /// A finished game succeeds without moves.
     fn get_concrete_moves(&mut self, player: player_id, moves: &mut Vec<MoveCode>) -> Result<()> {
-        if player != self.player_id() {
+        if !SimpleGame::check_to_move(self, player)? {
             return Ok(());
         }
 
-        for mov in 1..=self.max_sub.min(self.counter) {
-            moves.push(move_code::from(mov).into());
-        }
+        moves.extend_codes((1..=self.max_sub.min(self.counter)).map(move_code::from));
         Ok(())
     }
 
@@ -270,27 +287,22 @@ impl GameMethods for Nim {
                 "this player is not to move\0",
             ));
         }
-        sub_too_large(mov.md as Counter, self.counter)?;
+        sub_too_large(move_code_to(mov.md)?, self.counter)?;
         Ok(())
     }
 
     fn make_move(&mut self, _player: player_id, mov: MoveDataSync<u64>) -> Result<()> {
-        self.counter -= mov.md as Counter;
+        self.counter -= move_code_to::<Counter>(mov.md)?;
         self.turn = !self.turn;
         Ok(())
     }
 
     fn get_results(&mut self, players: &mut Vec<player_id>) -> Result<()> {
-        if self.counter == 0 {
-            players.push(self.player_id());
-        }
-        Ok(())
+        self.zero_sum_results(players)
     }
 
     fn get_move_data(&mut self, _player: player_id, string: &str) -> Result<MoveCode> {
-        let mov: Counter = string.parse().map_err(|e| {
-            Error::new_dynamic(ErrorCode::InvalidInput, format!("move parsing error: {e}"))
-        })?;
+        let mov: Counter = string.parse().context("move parsing error")?;
         sub_too_large(mov, self.max_sub)?;
         Ok(move_code::from(mov).into())
     }
@@ -310,6 +322,25 @@ impl GameMethods for Nim {
         writeln!(str_buf).expect("failed to write print buffer");
         Ok(())
     }
+
+    /// The binary state only needs to be restorable by [`Self::create()`].
+    fn serialize(&mut self, _player: player_id, buf: &mut Vec<u8>) -> Result<()> {
+        buf.extend_from_slice(&self.counter.to_le_bytes());
+        buf.extend_from_slice(&self.max_sub.to_le_bytes());
+        buf.extend_from_slice(&self.initial_counter.to_le_bytes());
+        buf.push(self.turn.into());
+        Ok(())
+    }
+
+    /// Expose the internal fields for debugging.
+    fn debug_print(&mut self, str_buf: &mut ValidCString) -> Result<()> {
+        writeln!(
+            str_buf,
+            "counter: {}, max_sub: {}, initial_counter: {}, turn: {}",
+            self.counter, self.max_sub, self.initial_counter, self.turn
+        )?;
+        Ok(())
+    }
 }
 
 /// This function creates the [`Metadata`] struct for describing _Nim_.
@@ -320,14 +351,12 @@ fn example_metadata() -> Metadata {
         game_name: cstr("Nim\0"),
         variant_name: cstr("Standard\0"),
         impl_name: cstr("mirabel_rs\0"),
-        version: semver {
-            major: 0,
-            minor: 1,
-            patch: 0,
-        },
+        version: semver_from_cargo!(),
         features: GameFeatures {
             options: true,
             print: true,
+            debug_print: true,
+            serializable: true,
             ..Default::default()
         },
     }
@@ -1,6 +1,12 @@
 //! Helpers for error handling in plugin APIs.
 
-use std::{ffi::c_char, fmt::Display, num::NonZeroU32, ptr::null};
+use std::{
+    ffi::c_char,
+    fmt::Display,
+    num::{NonZeroU32, ParseIntError, TryFromIntError},
+    ptr::null,
+    str::Utf8Error,
+};
 
 use super::{
     cstr, cstr_to_rust,
@@ -31,6 +37,17 @@ impl From<&ErrorString> for *const c_char {
     }
 }
 
+impl Display for ErrorString {
+    /// Prints the message or nothing for [`ErrorString::None`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorString::None => Ok(()),
+            ErrorString::Static(s) => write!(f, "{s}"),
+            ErrorString::Dynamic(s) => write!(f, "{s}"),
+        }
+    }
+}
+
 /// Error type for API functions.
 ///
 /// The APIs always expect an error code and optionally an error message.
@@ -75,6 +92,92 @@ impl Error {
             message: ErrorString::Dynamic(message.try_into().expect("msg")),
         }
     }
+
+    /// Prepend `ctx` to the message of this error.
+    ///
+    /// The result is always a dynamic error string of the form
+    /// `"<ctx>: <message>"` or just `ctx` if there was no message.
+    ///
+    /// # Panics
+    /// Panics if `ctx` contains a NUL byte.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::error::*;
+    /// let error = Error::new_static(ErrorCode::InvalidInput, "too large\0").context("counter");
+    /// assert_eq!("counter: too large", error.message.to_string());
+    /// ```
+    pub fn context(self, ctx: &str) -> Self {
+        let message = match self.message {
+            ErrorString::None => ctx.to_owned(),
+            message => format!("{ctx}: {message}"),
+        };
+        Self::new_dynamic(self.code, message)
+    }
+}
+
+/// Extension trait for adding context to errors of [`Result`]s.
+///
+/// # Example
+/// ```
+/// # use mirabel::error::*;
+/// let error = "abc".parse::<u16>().context("counter").unwrap_err();
+/// assert_eq!(ErrorCode::InvalidInput, error.code);
+/// assert_eq!("counter: invalid digit found in string", error.message.to_string());
+/// ```
+pub trait ResultExt<T> {
+    /// Convert the error into an [`Error`] and add `ctx` using
+    /// [`Error::context()`].
+    fn context(self, ctx: &str) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> ResultExt<T> for std::result::Result<T, E> {
+    #[inline]
+    fn context(self, ctx: &str) -> Result<T> {
+        self.map_err(|e| e.into().context(ctx))
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.message {
+            ErrorString::None => write!(f, "{}", self.code),
+            _ => write!(f, "{}: {}", self.code, self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Maps parsing errors to [`ErrorCode::InvalidInput`] keeping their message.
+///
+/// # Example
+/// ```
+/// # use mirabel::error::*;
+/// fn parse() -> Result<u16> {
+///     Ok("abc".parse::<u16>()?)
+/// }
+///
+/// let error = parse().unwrap_err();
+/// assert_eq!(ErrorCode::InvalidInput, error.code);
+/// assert_eq!("invalid digit found in string", error.message.to_string());
+/// ```
+impl From<ParseIntError> for Error {
+    fn from(error: ParseIntError) -> Self {
+        Self::new_dynamic(ErrorCode::InvalidInput, error.to_string())
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(error: Utf8Error) -> Self {
+        Self::new_dynamic(ErrorCode::InvalidInput, error.to_string())
+    }
+}
+
+impl From<std::fmt::Error> for Error {
+    fn from(error: std::fmt::Error) -> Self {
+        Self::new_dynamic(ErrorCode::InvalidInput, error.to_string())
+    }
 }
 
 impl From<ErrorCode> for Error {
@@ -144,6 +247,48 @@ impl From<NonZeroU32> for ErrorCode {
     }
 }
 
+impl TryFrom<error_code> for ErrorCode {
+    type Error = TryFromIntError;
+
+    /// Classify an [`error_code`] returned by a plugin.
+    ///
+    /// Fails for [`ERR_ERR_OK`](sys::ERR_ERR_OK) as this is not an error.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, sys::{self, error_code}};
+    /// let codes = [
+    ///     ErrorCode::Nok,
+    ///     ErrorCode::StateUnrecoverable,
+    ///     ErrorCode::StateCorrupted,
+    ///     ErrorCode::OutOfMemory,
+    ///     ErrorCode::FeatureUnsupported,
+    ///     ErrorCode::MissingHiddenState,
+    ///     ErrorCode::InvalidInput,
+    ///     ErrorCode::InvalidPlayer,
+    ///     ErrorCode::InvalidMove,
+    ///     ErrorCode::InvalidOptions,
+    ///     ErrorCode::InvalidLegacy,
+    ///     ErrorCode::InvalidState,
+    ///     ErrorCode::Unenumerable,
+    ///     ErrorCode::UnstablePosition,
+    ///     ErrorCode::SyncCounterMismatch,
+    ///     ErrorCode::SyncCounterImpossibleReorder,
+    ///     ErrorCode::Retry,
+    ///     ErrorCode::CustomAny,
+    ///     ErrorCode::Custom(CustomCode::new(sys::ERR_ERR_ENUM_DEFAULT_OFFSET).unwrap()),
+    /// ];
+    /// for code in codes {
+    ///     assert_eq!(code, ErrorCode::try_from(error_code::from(code)).unwrap());
+    /// }
+    /// assert!(ErrorCode::try_from(sys::ERR_ERR_OK).is_err());
+    /// ```
+    #[inline]
+    fn try_from(code: error_code) -> std::result::Result<Self, Self::Error> {
+        NonZeroU32::try_from(code).map(Into::into)
+    }
+}
+
 impl From<ErrorCode> for error_code {
     #[inline]
     fn from(error: ErrorCode) -> Self {
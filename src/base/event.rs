@@ -1,18 +1,23 @@
 //! Wrapper for the _mirabel_ event framework.
 
-use crate::MoveDataSync;
+use crate::{debug_big_move, debug_move_code, move_data_view, MoveDataSync, MoveDataView};
 
 pub use super::{sys::game_methods, sys::move_code, sys::player_id};
 
 use std::{
+    fmt::{self, Write},
     mem::MaybeUninit,
     ops::{Deref, DerefMut},
-    ptr::null_mut,
-    slice::from_raw_parts,
+    ptr::{null, null_mut},
+    str::from_utf8,
 };
 
 use super::{
-    game_init::GameInit, sys::move_data_s__bindgen_ty_1 as move_data_cl, sys::*, ValidCStr,
+    error::{Error, ErrorCode, Result},
+    game_init::GameInit,
+    sys::move_data_s__bindgen_ty_1 as move_data_cl,
+    sys::*,
+    ValidCStr, ValidCString,
 };
 
 /// Wrapper for an owned [`event_any`].
@@ -40,6 +45,40 @@ impl EventAny {
         unsafe { EventEnum::new(self) }
     }
 
+    /// Return the player of a game move event or [`None`] for other events.
+    ///
+    /// This is cheaper than matching on [`Self::to_rust()`].
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{event::*, sys::*};
+    /// let mut raw = event_any::default();
+    /// raw.game_move.base.type_ = EVENT_TYPE_E_EVENT_TYPE_GAME_MOVE;
+    /// raw.game_move.player = 2;
+    /// raw.game_move.data.sync_ctr = 7;
+    /// let event = unsafe { EventAny::new(raw) };
+    /// assert_eq!(Some(2), event.game_move_player());
+    /// assert_eq!(Some(7), event.sync_ctr());
+    /// // The raw event does not own any data.
+    /// std::mem::forget(event);
+    /// ```
+    #[inline]
+    pub fn game_move_player(&self) -> Option<player_id> {
+        self.game_move().map(|e| e.player)
+    }
+
+    /// Return the sync counter of a game move event or [`None`] for other
+    /// events.
+    #[inline]
+    pub fn sync_ctr(&self) -> Option<u64> {
+        self.game_move().map(|e| e.data.sync_ctr)
+    }
+
+    #[inline]
+    fn game_move(&self) -> Option<&event_game_move> {
+        (self.get_type() == EVENT_TYPE_E_EVENT_TYPE_GAME_MOVE).then(|| unsafe { &self.0.game_move })
+    }
+
     /// Create a new game move event by coping from the `player` and the `mov`.
     pub fn new_game_move(player: player_id, mov: MoveDataSync<MoveData>) -> Self {
         let mut event = MaybeUninit::<event_any>::uninit();
@@ -48,6 +87,233 @@ impl EventAny {
         }
         unsafe { Self(event.assume_init()) }
     }
+
+    /// Encode this event for sending it over the network.
+    ///
+    /// Only game move, state, and unload events are supported.
+    /// Load events reference local [`game_methods`] and cannot be sent.
+    /// All other events return [`ErrorCode::FeatureUnsupported`].
+    /// See [`EventMessage`] for the format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let base = unsafe { &self.base };
+        let payload = match self.to_rust() {
+            EventEnum::GameUnload(_) => EventPayload::GameUnload,
+            EventEnum::GameState(event) => EventPayload::GameState(event.state.map(<&str>::from)),
+            EventEnum::GameMove(event) => EventPayload::GameMove(event.player, event.data),
+            _ => {
+                return Err(Error::new_static(
+                    ErrorCode::FeatureUnsupported,
+                    "event type cannot be serialized\0",
+                ))
+            }
+        };
+        let message = EventMessage {
+            client_id: base.client_id,
+            lobby_id: base.lobby_id,
+            payload,
+        };
+        let mut bytes = vec![];
+        message.encode(&mut bytes);
+        Ok(bytes)
+    }
+
+    /// Decode an event created by [`Self::to_bytes()`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let message = EventMessage::decode(bytes)?;
+
+        let mut event = MaybeUninit::<event_any>::uninit();
+        match message.payload {
+            EventPayload::GameUnload => unsafe {
+                event_create_type(event.as_mut_ptr(), EVENT_TYPE_E_EVENT_TYPE_GAME_UNLOAD);
+            },
+            EventPayload::GameState(state) => {
+                let state = match state {
+                    None => None,
+                    Some(state) => {
+                        let mut buf = ValidCString::default();
+                        buf.write_str(state)?;
+                        Some(buf)
+                    }
+                };
+                let state = state.as_ref().map_or(null(), |s| s.as_ptr());
+                unsafe { event_create_game_state(event.as_mut_ptr(), state) };
+            }
+            EventPayload::GameMove(player, mov) => unsafe {
+                event_create_game_move(event.as_mut_ptr(), player, mov.into());
+            },
+        }
+
+        let mut event = unsafe { Self(event.assume_init()) };
+        unsafe {
+            event.base.client_id = message.client_id;
+            event.base.lobby_id = message.lobby_id;
+        }
+        Ok(event)
+    }
+}
+
+/// Network representation of an [`EventAny`].
+///
+/// The format is the little-endian type, client id, and lobby id followed by
+/// the type-specific payload.
+/// Unlike [`EventAny`], this does not need _mirabel_ for creating events.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::ErrorCode, event::*, MoveDataSync};
+/// let message = EventMessage {
+///     client_id: 1,
+///     lobby_id: 2,
+///     payload: EventPayload::GameMove(3, MoveDataSync::with_ctr(MoveData::BigMove(&[4, 5]), 7)),
+/// };
+/// let mut bytes = vec![];
+/// message.encode(&mut bytes);
+/// assert_eq!(message, EventMessage::decode(&bytes).unwrap());
+///
+/// let state = EventMessage {
+///     payload: EventPayload::GameState(Some("3 left")),
+///     ..message
+/// };
+/// bytes.clear();
+/// state.encode(&mut bytes);
+/// assert_eq!(state, EventMessage::decode(&bytes).unwrap());
+///
+/// // Trailing bytes are rejected.
+/// for payload in [EventPayload::GameUnload, EventPayload::GameState(None)] {
+///     bytes.clear();
+///     EventMessage { payload, ..message }.encode(&mut bytes);
+///     assert!(EventMessage::decode(&bytes).is_ok());
+///     bytes.push(0);
+///     let error = EventMessage::decode(&bytes).unwrap_err();
+///     assert_eq!(ErrorCode::InvalidInput, error.code);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventMessage<'l> {
+    pub client_id: u32,
+    pub lobby_id: u32,
+    pub payload: EventPayload<'l>,
+}
+
+/// Type-specific part of an [`EventMessage`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventPayload<'l> {
+    GameUnload,
+    GameState(Option<&'l str>),
+    GameMove(player_id, MoveDataSync<MoveData<'l>>),
+}
+
+impl<'l> EventPayload<'l> {
+    #[inline]
+    fn get_type(&self) -> EVENT_TYPE {
+        match self {
+            Self::GameUnload => EVENT_TYPE_E_EVENT_TYPE_GAME_UNLOAD,
+            Self::GameState(_) => EVENT_TYPE_E_EVENT_TYPE_GAME_STATE,
+            Self::GameMove(..) => EVENT_TYPE_E_EVENT_TYPE_GAME_MOVE,
+        }
+    }
+}
+
+impl<'l> EventMessage<'l> {
+    /// Append the encoded message to `bytes`.
+    pub fn encode(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.payload.get_type().to_le_bytes());
+        bytes.extend(self.client_id.to_le_bytes());
+        bytes.extend(self.lobby_id.to_le_bytes());
+        match self.payload {
+            EventPayload::GameUnload => {}
+            EventPayload::GameState(None) => bytes.push(0),
+            EventPayload::GameState(Some(state)) => {
+                bytes.push(1);
+                bytes.extend_from_slice(state.as_bytes());
+            }
+            EventPayload::GameMove(player, mov) => {
+                bytes.push(player);
+                bytes.extend(mov.sync_ctr.to_le_bytes());
+                match mov.md {
+                    MoveData::MoveCode(code) => {
+                        bytes.push(0);
+                        bytes.extend(code.to_le_bytes());
+                    }
+                    MoveData::BigMove(data) => {
+                        bytes.push(1);
+                        bytes.extend_from_slice(data);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Decode a message created by [`Self::encode()`].
+    ///
+    /// Fails with [`ErrorCode::InvalidInput`] if `bytes` are malformed or
+    /// have trailing bytes.
+    pub fn decode(mut bytes: &'l [u8]) -> Result<Self> {
+        let type_ = EVENT_TYPE::from_le_bytes(take(&mut bytes)?);
+        let client_id = u32::from_le_bytes(take(&mut bytes)?);
+        let lobby_id = u32::from_le_bytes(take(&mut bytes)?);
+
+        let payload = match type_ {
+            EVENT_TYPE_E_EVENT_TYPE_GAME_UNLOAD => EventPayload::GameUnload,
+            EVENT_TYPE_E_EVENT_TYPE_GAME_STATE => match take(&mut bytes)? {
+                [0] => EventPayload::GameState(None),
+                [1] => {
+                    let state = from_utf8(take_rest(&mut bytes))?;
+                    if state.contains('\0') {
+                        return Err(malformed());
+                    }
+                    EventPayload::GameState(Some(state))
+                }
+                _ => return Err(malformed()),
+            },
+            EVENT_TYPE_E_EVENT_TYPE_GAME_MOVE => {
+                let [player] = take(&mut bytes)?;
+                let sync_ctr = u64::from_le_bytes(take(&mut bytes)?);
+                let md = match take(&mut bytes)? {
+                    [0] => MoveData::MoveCode(move_code::from_le_bytes(take(&mut bytes)?)),
+                    [1] => MoveData::BigMove(take_rest(&mut bytes)),
+                    _ => return Err(malformed()),
+                };
+                EventPayload::GameMove(player, MoveDataSync { md, sync_ctr })
+            }
+            _ => {
+                return Err(Error::new_static(
+                    ErrorCode::FeatureUnsupported,
+                    "event type cannot be deserialized\0",
+                ))
+            }
+        };
+        if !bytes.is_empty() {
+            return Err(malformed());
+        }
+
+        Ok(Self {
+            client_id,
+            lobby_id,
+            payload,
+        })
+    }
+}
+
+/// Split the first `N` bytes off `bytes`.
+fn take<const N: usize>(bytes: &mut &[u8]) -> Result<[u8; N]> {
+    if bytes.len() < N {
+        return Err(malformed());
+    }
+    let (head, tail) = bytes.split_at(N);
+    *bytes = tail;
+    Ok(head.try_into().expect("length checked"))
+}
+
+/// Take all remaining `bytes`.
+#[inline]
+fn take_rest<'l>(bytes: &mut &'l [u8]) -> &'l [u8] {
+    std::mem::take(bytes)
+}
+
+#[inline]
+fn malformed() -> Error {
+    Error::new_static(ErrorCode::InvalidInput, "malformed event bytes\0")
 }
 
 impl Deref for EventAny {
@@ -135,6 +401,12 @@ impl<'l> EventGameLoadMethods<'l> {
     }
 }
 
+/// A new state was imported into the running game.
+///
+/// _mirabel_ has no separate import event.
+/// Loading a saved position arrives either as
+/// [`EventEnum::GameLoadMethods`] carrying a [`GameInit`] or as this event,
+/// so frontends should reset their view on both.
 pub struct EventGameState<'l> {
     pub base: Event,
     pub state: Option<ValidCStr<'l>>,
@@ -169,26 +441,56 @@ impl<'l> EventGameMove<'l> {
 }
 
 /// Rust equivalent of a borrowed [`move_data`].
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum MoveData<'l> {
     MoveCode(move_code),
     BigMove(&'l [u8]),
 }
 
+impl<'l> fmt::Debug for MoveData<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MoveCode(code) => debug_move_code(f, *code),
+            Self::BigMove(bytes) => debug_big_move(f, bytes),
+        }
+    }
+}
+
 impl<'l> MoveData<'l> {
     /// Converts a valid [`move_data`] to a [`Self`] by shallow-copying.
     #[inline]
-    unsafe fn from_ref(md: &move_data) -> Self {
-        // A move is a big move iff data!=NULL.
-        if md.data.is_null() {
-            Self::MoveCode(md.cl.code)
-        } else if md.cl.len == 0 {
-            // Handle this case separately, just in case.
-            Self::BigMove(&[])
-        } else {
-            Self::BigMove(from_raw_parts(md.data, md.cl.len))
+    pub(crate) unsafe fn from_ref(md: &'l move_data) -> Self {
+        match move_data_view(md) {
+            MoveDataView::Code(code) => Self::MoveCode(code),
+            MoveDataView::Big(bytes) => Self::BigMove(bytes),
         }
     }
+
+    /// Wrap into a [`MoveDataSync`] with the [`SYNC_CTR_DEFAULT`].
+    ///
+    /// The default counter tells _surena_ that the move does not depend on a
+    /// particular state, which is right for perfect-information games.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{event::MoveData, sys::SYNC_CTR_DEFAULT};
+    /// let mov = MoveData::MoveCode(42).with_default_sync();
+    /// assert!(matches!(mov.md, MoveData::MoveCode(42)));
+    /// assert_eq!(SYNC_CTR_DEFAULT, mov.sync_ctr);
+    /// ```
+    #[inline]
+    pub fn with_default_sync(self) -> MoveDataSync<Self> {
+        MoveDataSync::with_default(self)
+    }
+
+    /// Wrap into a [`MoveDataSync`] with an explicit `sync_ctr`.
+    ///
+    /// Games with hidden information need the counter of the state the move
+    /// was chosen in.
+    #[inline]
+    pub fn with_sync(self, sync_ctr: u64) -> MoveDataSync<Self> {
+        MoveDataSync::with_ctr(self, sync_ctr)
+    }
 }
 
 impl<'l> From<MoveData<'l>> for move_data {
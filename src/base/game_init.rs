@@ -1,9 +1,10 @@
 //! Wrapper around [`game_init`].
 
-use std::slice::from_raw_parts;
+use std::{fmt::Display, slice::from_raw_parts, str::FromStr, str::SplitWhitespace};
 
 use super::{
     cstr_to_rust,
+    error::{Error, ErrorCode, Result},
     sys::{
         game_init, GAME_INIT_SOURCE_TYPE_E_GAME_INIT_SOURCE_TYPE_DEFAULT as SOURCE_TYPE_DEFAULT,
         GAME_INIT_SOURCE_TYPE_E_GAME_INIT_SOURCE_TYPE_SERIALIZED as SOURCE_TYPE_SERIALIZED,
@@ -47,4 +48,117 @@ impl<'l> GameInit<'l> {
             _ => unreachable!("unexpected SOURCE_TYPE"),
         }
     }
+
+    /// Create a [`Self::Standard`] without legacy string.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::game_init::GameInit;
+    /// let init = GameInit::standard(Some("10 3"), None);
+    /// assert_eq!(Some("10 3"), init.opts());
+    /// assert_eq!(None, init.state());
+    /// assert_eq!(None, init.bytes());
+    ///
+    /// let init = GameInit::serialized(&[1, 2]);
+    /// assert_eq!(Some(&[1, 2][..]), init.bytes());
+    /// assert_eq!(None, init.opts());
+    /// ```
+    #[inline]
+    pub fn standard(opts: Option<&'l str>, state: Option<&'l str>) -> Self {
+        Self::Standard {
+            opts,
+            legacy: None,
+            state,
+        }
+    }
+
+    /// Create a [`Self::Serialized`].
+    #[inline]
+    pub fn serialized(bytes: &'l [u8]) -> Self {
+        Self::Serialized(bytes)
+    }
+
+    /// The options string of a [`Self::Standard`] or [`None`] otherwise.
+    #[inline]
+    pub fn opts(&self) -> Option<&'l str> {
+        match self {
+            Self::Standard { opts, .. } => *opts,
+            _ => None,
+        }
+    }
+
+    /// The legacy string of a [`Self::Standard`] or [`None`] otherwise.
+    #[inline]
+    pub fn legacy(&self) -> Option<&'l str> {
+        match self {
+            Self::Standard { legacy, .. } => *legacy,
+            _ => None,
+        }
+    }
+
+    /// The state string of a [`Self::Standard`] or [`None`] otherwise.
+    #[inline]
+    pub fn state(&self) -> Option<&'l str> {
+        match self {
+            Self::Standard { state, .. } => *state,
+            _ => None,
+        }
+    }
+
+    /// The bytes of a [`Self::Serialized`] or [`None`] otherwise.
+    #[inline]
+    pub fn bytes(&self) -> Option<&'l [u8]> {
+        match self {
+            Self::Serialized(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+}
+
+/// Tokenizer for whitespace-separated options strings like `"21 3"`.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::ErrorCode, game_init::OptionsParser};
+/// let mut parser = OptionsParser::new("21 3");
+/// assert_eq!(21u16, parser.next_parsed("counter").unwrap());
+/// assert_eq!(3u16, parser.next_parsed("maximum subtrahend").unwrap());
+///
+/// let mut parser = OptionsParser::new("21");
+/// assert_eq!(21u16, parser.next_parsed("counter").unwrap());
+/// let error = parser.next_parsed::<u16>("maximum subtrahend").unwrap_err();
+/// assert_eq!(ErrorCode::InvalidInput, error.code);
+/// assert_eq!("missing maximum subtrahend", error.message.to_string());
+/// ```
+#[derive(Debug, Clone)]
+pub struct OptionsParser<'l> {
+    tokens: SplitWhitespace<'l>,
+}
+
+impl<'l> OptionsParser<'l> {
+    pub fn new(options: &'l str) -> Self {
+        Self {
+            tokens: options.split_whitespace(),
+        }
+    }
+
+    /// Parse the next token as `T`.
+    ///
+    /// Fails with [`ErrorCode::InvalidInput`] and `field_name` in the message
+    /// if the token is missing or cannot be parsed.
+    pub fn next_parsed<T>(&mut self, field_name: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let token = self.tokens.next().ok_or_else(|| {
+            Error::new_dynamic(ErrorCode::InvalidInput, format!("missing {field_name}"))
+        })?;
+        token.parse().map_err(|error| {
+            Error::new_dynamic(
+                ErrorCode::InvalidInput,
+                format!("invalid {field_name} {token:?}: {error}"),
+            )
+        })
+    }
 }
@@ -1,6 +1,13 @@
 //! Wrapper for the _mirabel_ log framework.
 
-use std::ffi::c_char;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    ffi::c_char,
+    fmt::{self, Write},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
 
 use super::sys;
 
@@ -9,11 +16,232 @@ use super::sys;
 /// To prevent accidental coloring, all log messages start with "+ ".
 /// The wrapper will append a newline for you.
 pub fn mirabel_log(msg: &str) {
-    let msg = format!("+ {msg}\n");
-    let msg = msg.as_bytes().as_ptr_range();
-    let start: *const u8 = msg.start;
-    let end: *const u8 = msg.end;
-    unsafe {
-        sys::mirabel_log(start.cast::<c_char>(), end.cast::<c_char>());
+    write_log(None, format_args!("{msg}"));
+}
+
+/// Log `msg` with `level` only the first time this is called with `key`.
+///
+/// This is useful for warnings in code which runs every frame.
+/// Keys are never forgotten, so they must be static and not, e.g., contain
+/// formatted values.
+///
+/// # Example
+/// ```
+/// # use std::{ffi::c_char, sync::atomic::{AtomicUsize, Ordering}};
+/// # static LOGGED: AtomicUsize = AtomicUsize::new(0);
+/// # #[no_mangle]
+/// # extern "C" fn mirabel_log(_: *const c_char, _: *const c_char) {
+/// #     LOGGED.fetch_add(1, Ordering::Relaxed);
+/// # }
+/// use mirabel::log::{log_once, LogLevel};
+///
+/// for _ in 0..3 {
+///     log_once(LogLevel::Warn, "missing texture", "texture not found");
+/// }
+/// assert_eq!(1, LOGGED.load(Ordering::Relaxed));
+/// ```
+pub fn log_once(level: LogLevel, key: &'static str, msg: &str) {
+    if due(key, None) {
+        log(level, msg);
+    }
+}
+
+/// Log `msg` with `level` at most once per `interval` for the same `key`.
+///
+/// Like for [`log_once()`], keys must be static.
+///
+/// # Example
+/// ```
+/// # use std::{ffi::c_char, sync::atomic::{AtomicUsize, Ordering}};
+/// # static LOGGED: AtomicUsize = AtomicUsize::new(0);
+/// # #[no_mangle]
+/// # extern "C" fn mirabel_log(_: *const c_char, _: *const c_char) {
+/// #     LOGGED.fetch_add(1, Ordering::Relaxed);
+/// # }
+/// use mirabel::log::{log_every, LogLevel::Info};
+/// use std::{thread::sleep, time::Duration};
+///
+/// let interval = Duration::from_millis(50);
+/// log_every(Info, interval, "slow frame", "frame took too long");
+/// log_every(Info, interval, "slow frame", "frame took too long");
+/// assert_eq!(1, LOGGED.load(Ordering::Relaxed));
+///
+/// // Keys are independent of each other.
+/// log_every(Info, interval, "lost packet", "packet lost");
+/// assert_eq!(2, LOGGED.load(Ordering::Relaxed));
+///
+/// sleep(interval);
+/// log_every(Info, interval, "slow frame", "frame took too long");
+/// assert_eq!(3, LOGGED.load(Ordering::Relaxed));
+/// ```
+pub fn log_every(level: LogLevel, interval: Duration, key: &'static str, msg: &str) {
+    if due(key, Some(interval)) {
+        log(level, msg);
+    }
+}
+
+/// Last log time per key for [`log_once()`] and [`log_every()`].
+static LAST_LOGGED: Mutex<Option<HashMap<&'static str, Instant>>> = Mutex::new(None);
+
+/// Check whether `key` should be logged now and record it if so.
+///
+/// Without `interval`, keys are only logged once.
+fn due(key: &'static str, interval: Option<Duration>) -> bool {
+    let mut map = LAST_LOGGED.lock().unwrap_or_else(|e| e.into_inner());
+    let map = map.get_or_insert_with(HashMap::new);
+    let now = Instant::now();
+    match map.get_mut(key) {
+        None => {
+            map.insert(key, now);
+            true
+        }
+        Some(last) => match interval {
+            Some(interval) if now.duration_since(*last) >= interval => {
+                *last = now;
+                true
+            }
+            _ => false,
+        },
+    }
+}
+
+/// Severity of a log message.
+///
+/// _mirabel_ has only one log sink, so the level is prefixed to the message.
+///
+/// # Example
+/// ```
+/// # use mirabel::log::LogLevel;
+/// assert_eq!("[WARN]", LogLevel::Warn.to_string());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Debug => "[DEBUG]",
+            Self::Info => "[INFO]",
+            Self::Warn => "[WARN]",
+            Self::Error => "[ERROR]",
+        })
     }
 }
+
+/// Log `msg` with `level` via the _mirabel_ log framework.
+///
+/// Messages look like `"+ [WARN] msg\n"`.
+///
+/// # Example
+/// ```
+/// # use std::{ffi::c_char, sync::Mutex};
+/// # static LOGGED: Mutex<String> = Mutex::new(String::new());
+/// # #[no_mangle]
+/// # extern "C" fn mirabel_log(start: *const c_char, end: *const c_char) {
+/// #     let len = end as usize - start as usize;
+/// #     let msg = unsafe { std::slice::from_raw_parts(start.cast::<u8>(), len) };
+/// #     LOGGED.lock().unwrap().push_str(std::str::from_utf8(msg).unwrap());
+/// # }
+/// use mirabel::{log::*, log_error};
+///
+/// log(LogLevel::Warn, "low on memory");
+/// log_error!("{} moves failed", 2);
+/// mirabel_log("plain");
+/// assert_eq!(
+///     "+ [WARN] low on memory\n+ [ERROR] 2 moves failed\n+ plain\n",
+///     *LOGGED.lock().unwrap()
+/// );
+/// ```
+#[inline]
+pub fn log(level: LogLevel, msg: &str) {
+    log_fmt(level, format_args!("{msg}"));
+}
+
+/// Like [`log()`] but for formatting arguments.
+///
+/// This is used by the [`log_debug!()`](crate::log_debug),
+/// [`log_info!()`](crate::log_info), [`log_warn!()`](crate::log_warn), and
+/// [`log_error!()`](crate::log_error) macros.
+#[inline]
+pub fn log_fmt(level: LogLevel, args: fmt::Arguments) {
+    write_log(Some(level), args);
+}
+
+thread_local! {
+    /// Reused for formatting log messages without allocating every time.
+    static LOG_BUF: RefCell<String> = RefCell::new(String::new());
+}
+
+/// Format the message into `buf`.
+fn format_log(buf: &mut String, level: Option<LogLevel>, args: fmt::Arguments) {
+    buf.clear();
+    buf.push_str("+ ");
+    if let Some(level) = level {
+        write!(buf, "{level} ").expect("failed to format log level");
+    }
+    buf.write_fmt(args).expect("failed to format log message");
+    buf.push('\n');
+}
+
+fn write_log(level: Option<LogLevel>, args: fmt::Arguments) {
+    LOG_BUF.with(|buf| {
+        // A nested log call from a Display impl would panic on borrow_mut.
+        let mut local = String::new();
+        let mut guard = buf.try_borrow_mut();
+        let buf = match guard {
+            Ok(ref mut buf) => &mut **buf,
+            Err(_) => &mut local,
+        };
+        format_log(buf, level, args);
+
+        let msg = buf.as_bytes().as_ptr_range();
+        let start: *const u8 = msg.start;
+        let end: *const u8 = msg.end;
+        unsafe {
+            sys::mirabel_log(start.cast::<c_char>(), end.cast::<c_char>());
+        }
+    });
+}
+
+/// Log a formatted message with [`LogLevel::Debug`].
+///
+/// # Example
+/// ```no_run
+/// # use mirabel::log_debug;
+/// log_debug!("counter is {}", 42);
+/// ```
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        $crate::log::log_fmt($crate::log::LogLevel::Debug, ::std::format_args!($($arg)*))
+    };
+}
+
+/// Log a formatted message with [`LogLevel::Info`].
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)*) => {
+        $crate::log::log_fmt($crate::log::LogLevel::Info, ::std::format_args!($($arg)*))
+    };
+}
+
+/// Log a formatted message with [`LogLevel::Warn`].
+#[macro_export]
+macro_rules! log_warn {
+    ($($arg:tt)*) => {
+        $crate::log::log_fmt($crate::log::LogLevel::Warn, ::std::format_args!($($arg)*))
+    };
+}
+
+/// Log a formatted message with [`LogLevel::Error`].
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)*) => {
+        $crate::log::log_fmt($crate::log::LogLevel::Error, ::std::format_args!($($arg)*))
+    };
+}
@@ -7,13 +7,15 @@ pub mod game_init;
 pub mod string;
 pub mod sys;
 
+mod version;
+
 #[cfg(feature = "mirabel")]
 pub mod event;
 
 #[cfg(feature = "mirabel")]
 pub mod log;
 
-use std::slice::from_raw_parts;
+use std::{fmt, slice::from_raw_parts};
 
 pub use string::*;
 
@@ -51,6 +53,34 @@ impl<M> MoveDataSync<M> {
             sync_ctr: sys::SYNC_CTR_DEFAULT,
         }
     }
+
+    /// Create a new [`Self`] with an explicit `sync_ctr`.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::MoveDataSync;
+    /// assert_eq!(MoveDataSync { md: 42, sync_ctr: 3 }, MoveDataSync::with_ctr(42, 3));
+    /// ```
+    #[inline]
+    pub fn with_ctr(md: M, sync_ctr: u64) -> Self {
+        Self { md, sync_ctr }
+    }
+}
+
+impl<M: fmt::Display> fmt::Display for MoveDataSync<M> {
+    /// Formats as `md@sync_ctr` for compact logging.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{MoveDataSync, sys::SYNC_CTR_DEFAULT};
+    /// assert_eq!(
+    ///     format!("42@{SYNC_CTR_DEFAULT}"),
+    ///     MoveDataSync::with_default(42).to_string(),
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.md, self.sync_ctr)
+    }
 }
 
 impl<M: Into<sys::move_data>> From<MoveDataSync<M>> for sys::move_data_sync {
@@ -63,10 +93,30 @@ impl<M: Into<sys::move_data>> From<MoveDataSync<M>> for sys::move_data_sync {
     }
 }
 
-/// Wrapper around [`from_raw_parts`].
+/// Wrapper around [`from_raw_parts`] for slices handed over by C code.
+///
+/// This always safely returns an empty slice if `len` is zero, even if
+/// `pointer` is NULL or dangling.
+/// C APIs often pass NULL for empty arrays, which [`from_raw_parts`] does not
+/// allow.
 ///
-/// This always safely returns an empty slice if `len` is zero.
-pub(crate) unsafe fn from_raw_hedged<'l, T>(pointer: *const T, len: usize) -> &'l [T] {
+/// # Safety
+/// If `len` is not zero, `pointer` must satisfy the requirements of
+/// [`from_raw_parts`].
+///
+/// # Example
+/// ```
+/// # use mirabel::slice_from_raw_hedged;
+/// use std::ptr::null;
+///
+/// let data = [1, 2, 3];
+/// unsafe {
+///     assert!(slice_from_raw_hedged::<u8>(null(), 0).is_empty());
+///     assert!(slice_from_raw_hedged(data.as_ptr(), 0).is_empty());
+///     assert_eq!(&data, slice_from_raw_hedged(data.as_ptr(), 3));
+/// }
+/// ```
+pub unsafe fn slice_from_raw_hedged<'l, T>(pointer: *const T, len: usize) -> &'l [T] {
     if len == 0 {
         &[]
     } else {
@@ -74,6 +124,76 @@ pub(crate) unsafe fn from_raw_hedged<'l, T>(pointer: *const T, len: usize) -> &'
     }
 }
 
+/// Borrowed view of a [`move_data`](sys::move_data) with the union resolved.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MoveDataView<'l> {
+    Code(sys::move_code),
+    Big(&'l [u8]),
+}
+
+impl<'l> fmt::Debug for MoveDataView<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Code(code) => debug_move_code(f, *code),
+            Self::Big(bytes) => debug_big_move(f, bytes),
+        }
+    }
+}
+
+/// Resolve the union of `mov` into a [`MoveDataView`].
+///
+/// This is the one place which decides between move codes and big moves:
+/// A move is a big move iff `data` is not NULL.
+/// Empty big moves have a `len` of zero.
+///
+/// # Safety
+/// `mov` must be a valid move, i.e., `data` must point to `len` readable
+/// bytes for big moves.
+///
+/// # Example
+/// ```
+/// # use mirabel::{game::MoveCode, move_data_view, MoveDataView};
+/// let code = *MoveCode::from(7);
+/// assert_eq!(MoveDataView::Code(7), unsafe { move_data_view(&code) });
+///
+/// let mut bytes = [1u8, 2];
+/// let mut big = code;
+/// big.cl.len = 0;
+/// big.data = bytes.as_mut_ptr();
+/// assert_eq!(MoveDataView::Big(&[]), unsafe { move_data_view(&big) });
+/// big.cl.len = bytes.len();
+/// assert_eq!(MoveDataView::Big(&[1, 2]), unsafe { move_data_view(&big) });
+/// ```
+#[inline]
+pub unsafe fn move_data_view(mov: &sys::move_data) -> MoveDataView<'_> {
+    if mov.data.is_null() {
+        MoveDataView::Code(mov.cl.code)
+    } else {
+        MoveDataView::Big(slice_from_raw_hedged(mov.data, mov.cl.len))
+    }
+}
+
+/// [`Debug`](fmt::Debug) helper for move codes which are printed in hex.
+pub(crate) fn debug_move_code(f: &mut fmt::Formatter<'_>, code: sys::move_code) -> fmt::Result {
+    write!(f, "MoveCode({code:#x})")
+}
+
+/// [`Debug`](fmt::Debug) helper for big moves.
+///
+/// Only a short preview of the bytes is printed to avoid flooding logs.
+pub(crate) fn debug_big_move(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    const PREVIEW: usize = 8;
+
+    write!(f, "BigMove([len {}]", bytes.len())?;
+    for byte in bytes.iter().take(PREVIEW) {
+        write!(f, " {byte:02x}")?;
+    }
+    if bytes.len() > PREVIEW {
+        write!(f, " ..")?;
+    }
+    write!(f, ")")
+}
+
 /// Simple macro for counting the number of provided arguments.
 ///
 /// # Example
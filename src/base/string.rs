@@ -3,11 +3,11 @@
 use std::{
     ffi::{c_char, CStr, CString, FromBytesWithNulError, NulError},
     fmt::{self, Debug, Display, Write},
+    io,
     marker::PhantomData,
-    mem::take,
     ops::Deref,
     ptr::NonNull,
-    str::from_utf8_unchecked,
+    str::{from_utf8, from_utf8_unchecked, Lines, SplitWhitespace, Utf8Error},
 };
 
 /// Simple helper function to create a [`ValidCStr`] from an [`str`].
@@ -33,6 +33,14 @@ pub fn cstr(string: &str) -> ValidCStr {
 ///
 /// You must sure that the returned lifetime does not outlive the input data.
 ///
+/// # Variants
+/// - [`cstr_to_rust()`]: checks for NULL, UTF-8 is only validated in debug
+///   builds.
+/// - [`cstr_to_rust_unchecked()`]: like [`cstr_to_rust()`] without the NULL
+///   check.
+/// - [`cstr_to_bytes()`]: checks for NULL but never looks at the encoding.
+///   Use this for binary-ish data which might not be valid UTF-8.
+///
 /// # Example
 /// ```
 /// # use mirabel::cstr_to_rust;
@@ -65,6 +73,34 @@ pub unsafe fn cstr_to_rust_unchecked<'l>(cstr: *const c_char) -> &'l str {
     }
 }
 
+/// Converts raw C string pointers into byte slices without the NUL.
+///
+/// Unlike [`cstr_to_rust()`], the data does not need to be valid UTF-8.
+///
+/// # Safety
+/// This requires a NULL pointer or a pointer to a NUL-terminated string.
+///
+/// You must sure that the returned lifetime does not outlive the input data.
+///
+/// # Example
+/// ```
+/// # use mirabel::cstr_to_bytes;
+/// # use std::{ptr::null, ffi::c_char};
+/// # unsafe {
+/// assert_eq!(None, cstr_to_bytes(null()));
+/// let cstr = (&[0xff, b'\0']).as_ptr().cast(); // not valid UTF-8
+/// assert_eq!(Some(&[0xff][..]), cstr_to_bytes(cstr));
+/// # }
+/// ```
+#[inline]
+pub unsafe fn cstr_to_bytes<'l>(cstr: *const c_char) -> Option<&'l [u8]> {
+    if cstr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(cstr).to_bytes())
+    }
+}
+
 /// A C-style string with guarantees and lifetime.
 ///
 /// This simply wraps a char pointer but guarantees that it is not NULL,
@@ -89,6 +125,42 @@ impl<'s> ValidCStr<'s> {
             phantom: Default::default(),
         })
     }
+
+    /// Validate the string as UTF-8 and return it as [`str`].
+    ///
+    /// This double-checks strings which were handed over by C code.
+    #[inline]
+    pub fn as_str(&self) -> Result<&'s str, Utf8Error> {
+        Into::<&'s CStr>::into(*self).to_str()
+    }
+
+    /// Split the string at whitespace after validating it once.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::cstr;
+    /// let options = cstr("21  3\ttrue\0");
+    /// let tokens: Vec<_> = options.split_whitespace().unwrap().collect();
+    /// assert_eq!(["21", "3", "true"], tokens[..]);
+    /// ```
+    #[inline]
+    pub fn split_whitespace(&self) -> Result<SplitWhitespace<'s>, Utf8Error> {
+        self.as_str().map(str::split_whitespace)
+    }
+
+    /// Iterate over the lines of the string after validating it once.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::cstr;
+    /// let state = cstr("a 21\nb 3\0");
+    /// let lines: Vec<_> = state.lines().unwrap().collect();
+    /// assert_eq!(["a 21", "b 3"], lines[..]);
+    /// ```
+    #[inline]
+    pub fn lines(&self) -> Result<Lines<'s>, Utf8Error> {
+        self.as_str().map(str::lines)
+    }
 }
 
 impl<'s> Display for ValidCStr<'s> {
@@ -103,6 +175,15 @@ impl<'s> Debug for ValidCStr<'s> {
     }
 }
 
+impl<'s> PartialEq for ValidCStr<'s> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Into::<&CStr>::into(*self) == Into::<&CStr>::into(*other)
+    }
+}
+
+impl<'s> Eq for ValidCStr<'s> {}
+
 impl<'l> TryFrom<&'l str> for ValidCStr<'l> {
     type Error = FromBytesWithNulError;
 
@@ -132,11 +213,105 @@ impl<'l> From<ValidCStr<'l>> for &'l str {
     }
 }
 
-/// Owned variant of [`ValidCStr`] which simply wraps a [`CString`].
+/// Owned variant of [`ValidCStr`].
 ///
-/// It additionally guarantees that the character sequence is valid UTF-8.
-#[derive(Default)]
-pub struct ValidCString(CString);
+/// It guarantees that the character sequence is NUL-terminated, contains no
+/// other NUL byte, and is valid UTF-8.
+/// Unlike a [`CString`], it keeps its buffer on [`Self::clear()`] so that it
+/// can be reused without reallocating.
+pub struct ValidCString(
+    /// Always ends with the only NUL byte.
+    Vec<u8>,
+    /// Incomplete UTF-8 sequence held back by [`io::Write`].
+    PartialChar,
+);
+
+/// Start of a UTF-8 encoded character which was only written partly.
+#[derive(Clone, Copy, Default)]
+struct PartialChar {
+    bytes: [u8; 4],
+    len: usize,
+}
+
+/// Length of the UTF-8 sequence starting with `lead`.
+///
+/// `lead` must be the lead byte of a multibyte sequence.
+#[inline]
+fn utf8_width(lead: u8) -> usize {
+    match lead {
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
+    }
+}
+
+impl ValidCString {
+    /// Truncate to the empty string while keeping the allocation.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::ValidCString;
+    /// use std::fmt::Write;
+    ///
+    /// let mut string = ValidCString::default();
+    /// write!(string, "some state").unwrap();
+    /// let capacity = string.capacity();
+    /// string.clear();
+    /// assert_eq!(b"", string.as_bytes());
+    /// assert_eq!(capacity, string.capacity());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear();
+        self.0.push(0);
+        self.1 = Default::default();
+    }
+
+    /// Create a string from arbitrary bytes.
+    ///
+    /// Invalid UTF-8 sequences are replaced with U+FFFD and interior NUL bytes
+    /// are dropped.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::ValidCString;
+    /// let string = ValidCString::from_utf8_lossy(b"a\xffb\0c");
+    /// assert_eq!("a\u{fffd}bc", AsRef::<str>::as_ref(&string));
+    /// ```
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Self {
+        let mut buf: Vec<u8> = String::from_utf8_lossy(bytes)
+            .bytes()
+            .filter(|&b| b != 0)
+            .collect();
+        buf.push(0);
+        Self(buf, Default::default())
+    }
+
+    /// Capacity of the underlying buffer including the NUL byte.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// The contained bytes without the trailing NUL byte.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0[..self.0.len() - 1]
+    }
+
+    /// The contained bytes including the trailing NUL byte.
+    #[inline]
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Default for ValidCString {
+    #[inline]
+    fn default() -> Self {
+        Self(vec![0], Default::default())
+    }
+}
 
 impl TryFrom<String> for ValidCString {
     type Error = NulError;
@@ -151,37 +326,154 @@ impl TryFrom<String> for ValidCString {
     /// assert_eq!("ValidCString", s);
     /// ```
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        CString::new(value).map(Self)
+        CString::new(value).map(|s| Self(s.into_bytes_with_nul(), Default::default()))
+    }
+}
+
+impl<'l> From<&'l ValidCString> for ValidCStr<'l> {
+    #[inline]
+    fn from(s: &'l ValidCString) -> Self {
+        // ValidCString guarantees the same invariants as ValidCStr.
+        unsafe { ValidCStr::new(s.as_ptr()).unwrap_unchecked() }
     }
 }
 
 impl AsRef<str> for ValidCString {
     #[inline]
     fn as_ref(&self) -> &str {
-        unsafe { from_utf8_unchecked(self.to_bytes()) }
+        unsafe { from_utf8_unchecked(self.as_bytes()) }
     }
 }
 
 impl Deref for ValidCString {
-    type Target = CString;
+    type Target = CStr;
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        &self.0
+        unsafe { CStr::from_bytes_with_nul_unchecked(&self.0) }
     }
 }
 
+/// Fails while a character written partly via [`io::Write`] is pending,
+/// because the string would be reordered otherwise.
 impl Write for ValidCString {
     #[inline]
     fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if self.1.len > 0 {
+            return Err(fmt::Error);
+        }
+        self.push_str(s)
+    }
+}
+
+/// Appends raw bytes to the string.
+///
+/// The written bytes must be valid UTF-8 and must not contain NUL bytes.
+/// Otherwise, an [`io::ErrorKind::InvalidInput`] error is returned and nothing
+/// is written.
+/// A character may be split across writes: an incomplete sequence at the end
+/// of a write is held back until the next write completes it.
+/// Pending bytes are not part of [`ValidCString::as_bytes()`] or the C string
+/// and are discarded by [`ValidCString::clear()`].
+/// Hence, [`io::Write::flush()`] fails with [`io::ErrorKind::InvalidData`]
+/// while a character is pending and so does [`fmt::Write::write_str()`].
+///
+/// # Example
+/// ```
+/// # use mirabel::ValidCString;
+/// use std::io::Write;
+///
+/// let mut string = ValidCString::default();
+/// string.write_all(b"21 3").unwrap();
+/// assert_eq!(b"21 3", string.as_bytes());
+/// assert_eq!(b"21 3\0", string.as_bytes_with_nul());
+/// assert!(string.write(b"\0").is_err());
+/// assert_eq!(b"21 3\0", string.as_bytes_with_nul());
+///
+/// // "é" is encoded as 0xc3 0xa9.
+/// string.clear();
+/// string.write_all(b"caf\xc3").unwrap();
+/// assert_eq!(b"caf", string.as_bytes());
+/// string.write_all(b"\xa9!").unwrap();
+/// assert_eq!("café!", AsRef::<str>::as_ref(&string));
+/// assert!(string.write(b"\xff").is_err());
+///
+/// // Nothing may be written in between the parts of a character.
+/// string.clear();
+/// string.write_all(b"\xc3").unwrap();
+/// assert!(string.flush().is_err());
+/// assert!(std::fmt::Write::write_str(&mut string, "x").is_err());
+/// string.write_all(b"\xa9").unwrap();
+/// string.flush().unwrap();
+/// assert_eq!("é", AsRef::<str>::as_ref(&string));
+/// ```
+impl io::Write for ValidCString {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.1.len > 0 {
+            return self.complete_char(buf);
+        }
+
+        let (valid, rest) = match from_utf8(buf) {
+            Ok(s) => (s, &[][..]),
+            Err(e) if e.error_len().is_none() => {
+                let (valid, rest) = buf.split_at(e.valid_up_to());
+                (unsafe { from_utf8_unchecked(valid) }, rest)
+            }
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidInput, e)),
+        };
+        self.push_str(valid)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "interior NUL byte"))?;
+        self.1.bytes[..rest.len()].copy_from_slice(rest);
+        self.1.len = rest.len();
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        if self.1.len > 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "incomplete UTF-8 character",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl ValidCString {
+    /// Append `s` regardless of pending bytes.
+    #[inline]
+    fn push_str(&mut self, s: &str) -> fmt::Result {
         if s.bytes().any(|b| b == 0) {
             return Err(fmt::Error);
         }
-        let mut bytes = take(self).0.into_bytes();
-        bytes.extend_from_slice(s.as_bytes());
-        *self = unsafe { Self(CString::from_vec_unchecked(bytes)) };
+        self.0.pop();
+        self.0.extend_from_slice(s.as_bytes());
+        self.0.push(0);
         Ok(())
     }
+
+    /// Continue the held back character with the start of `buf`.
+    fn complete_char(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let PartialChar { mut bytes, len } = self.1;
+        let width = utf8_width(bytes[0]);
+        let take = (width - len).min(buf.len());
+        bytes[len..len + take].copy_from_slice(&buf[..take]);
+        let len = len + take;
+
+        match from_utf8(&bytes[..len]) {
+            Ok(s) => {
+                self.push_str(s).expect("NUL in multibyte character");
+                self.1 = Default::default();
+            }
+            Err(e) if e.error_len().is_none() => self.1 = PartialChar { bytes, len },
+            Err(e) => {
+                self.1 = Default::default();
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, e));
+            }
+        }
+        Ok(take)
+    }
 }
 
 impl Display for ValidCString {
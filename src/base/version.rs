@@ -0,0 +1,129 @@
+//! Helpers for the [`semver`] struct.
+
+use std::{cmp::Ordering, fmt};
+
+use super::sys::semver;
+
+impl semver {
+    /// Shorthand for creating a [`semver`].
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::sys::semver;
+    /// assert!(semver::new(1, 2, 0) > semver::new(1, 1, 9));
+    /// assert!(semver::new(0, 9, 0) < semver::new(1, 0, 0));
+    /// assert_eq!("1.2.0", semver::new(1, 2, 0).to_string());
+    /// ```
+    #[inline]
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parse a `major.minor.patch` version at compile time.
+    ///
+    /// Pre-release and build suffixes like `-alpha` or `+build` are ignored.
+    /// Use [`semver_from_cargo!()`](crate::semver_from_cargo) for the
+    /// version of the current crate.
+    ///
+    /// # Panics
+    /// Panics on malformed versions, which fails compilation in constants.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::sys::semver;
+    /// const VERSION: semver = semver::parse("1.20.3-beta");
+    /// assert_eq!(semver::new(1, 20, 3), VERSION);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use mirabel::sys::semver;
+    /// const VERSION: semver = semver::parse("1.x.3");
+    /// ```
+    pub const fn parse(version: &str) -> Self {
+        let bytes = version.as_bytes();
+        let (major, i) = parse_component(bytes, 0);
+        let i = expect_dot(bytes, i);
+        let (minor, i) = parse_component(bytes, i);
+        let i = expect_dot(bytes, i);
+        let (patch, i) = parse_component(bytes, i);
+        if i < bytes.len() && bytes[i] != b'-' && bytes[i] != b'+' {
+            panic!("unexpected characters after version");
+        }
+        Self::new(major, minor, patch)
+    }
+}
+
+/// Parse a decimal number starting at `i` and return it with the next index.
+const fn parse_component(bytes: &[u8], mut i: usize) -> (u32, usize) {
+    let start = i;
+    let mut value: u32 = 0;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        let digit = (bytes[i] - b'0') as u32;
+        value = match value.checked_mul(10) {
+            Some(v) => match v.checked_add(digit) {
+                Some(v) => v,
+                None => panic!("version component too large"),
+            },
+            None => panic!("version component too large"),
+        };
+        i += 1;
+    }
+    if i == start {
+        panic!("missing version component");
+    }
+    (value, i)
+}
+
+/// Skip the `.` at `i`.
+const fn expect_dot(bytes: &[u8], i: usize) -> usize {
+    if i >= bytes.len() || bytes[i] != b'.' {
+        panic!("expected '.' in version");
+    }
+    i + 1
+}
+
+/// Create a [`semver`] from the `CARGO_PKG_VERSION` of the calling crate.
+///
+/// This keeps plugin metadata in sync with the crate version.
+/// Malformed versions fail to compile.
+///
+/// # Example
+/// ```
+/// # use mirabel::semver_from_cargo;
+/// let version = semver_from_cargo!();
+/// assert_eq!(env!("CARGO_PKG_VERSION_MAJOR").parse::<u32>().unwrap(), version.major);
+/// assert_eq!(env!("CARGO_PKG_VERSION_MINOR").parse::<u32>().unwrap(), version.minor);
+/// assert_eq!(env!("CARGO_PKG_VERSION_PATCH").parse::<u32>().unwrap(), version.patch);
+/// ```
+#[macro_export]
+macro_rules! semver_from_cargo {
+    () => {{
+        const VERSION: $crate::sys::semver =
+            $crate::sys::semver::parse(::std::env!("CARGO_PKG_VERSION"));
+        VERSION
+    }};
+}
+
+impl PartialOrd for semver {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for semver {
+    /// Compares lexicographically by major, minor, and patch version.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+    }
+}
+
+impl fmt::Display for semver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
@@ -2,8 +2,10 @@
 
 use std::{
     marker::PhantomData,
+    mem::{forget, MaybeUninit},
     os::raw::{c_char, c_void},
-    ptr::{addr_of, addr_of_mut, null_mut},
+    ptr::{addr_of, addr_of_mut, null, null_mut},
+    time::{Duration, Instant},
 };
 
 use crate::sdl_event::SDLEventEnum;
@@ -12,11 +14,12 @@ use crate::{
     cstr_to_rust,
     error::*,
     event::*,
+    slice_from_raw_hedged,
     sys::{
         self, error_code, event_any, event_queue, frontend_methods, game_feature_flags, semver,
         ERR_ERR_OK,
     },
-    ValidCStr,
+    MoveDataSync, ValidCStr,
 };
 
 #[cfg(feature = "skia")]
@@ -25,8 +28,15 @@ use super::skia_helper;
 #[cfg(feature = "skia")]
 pub use skia_safe as skia;
 
+#[cfg(feature = "skia")]
+pub use super::skia_helper::{check_gl_version, draw_highlight, gl, grid_path, parse_gl_version};
+
+pub use super::shortcuts::Shortcuts;
 pub use crate::sys::{frontend_display_data, frontend_feature_flags};
 
+/// Version of the _mirabel_ frontend API implemented by this crate.
+pub const FRONTEND_API_VERSION: u64 = sys::MIRABEL_FRONTEND_API_VERSION;
+
 /// This macro creates the `plugin_get_frontend_methods` function.
 ///
 /// Is must be supplied with all frontend structs and their [`Metadata`]
@@ -48,6 +58,11 @@ pub use crate::sys::{frontend_display_data, frontend_feature_flags};
 #[macro_export]
 macro_rules! plugin_get_frontend_methods {
     ( $( $f:ty{$m:expr} ),* ) => {
+        const _: () = ::std::assert!(
+            $crate::count!($($f),*) > 0,
+            "no frontend methods registered",
+        );
+
         static mut PLUGIN_FRONTEND_METHODS: ::std::mem::MaybeUninit<
             [$crate::sys::frontend_methods; $crate::count!($($f),*)]
         > = ::std::mem::MaybeUninit::uninit();
@@ -84,7 +99,7 @@ macro_rules! plugin_get_frontend_methods {
 
         #[no_mangle]
         extern "C" fn plugin_get_frontend_capi_version() -> u64 {
-            $crate::sys::MIRABEL_FRONTEND_API_VERSION
+            $crate::frontend::FRONTEND_API_VERSION
         }
     };
 }
@@ -114,16 +129,100 @@ macro_rules! mirabel_try {
 /// See the `example` crate in the project root.
 pub trait FrontendMethods: Sized {
     /// The associated type for storing the pre-create options.
+    ///
+    /// _mirabel_'s frontend API has no hooks for exporting or importing
+    /// these options.
+    /// Hence, they cannot be persisted across sessions by the host.
     type Options;
 
-    fn create(options: Option<&Self::Options>) -> Result<Self>;
+    /// `display` holds the initial geometry, e.g., for pre-allocating
+    /// framebuffer-sized resources.
+    /// Wrap it in [`DisplayData`] for typed access.
+    ///
+    /// # Migration
+    /// Previously, this only received `options`.
+    /// Add a `_display: &frontend_display_data` parameter to existing
+    /// implementations which do not need it.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, event::EventAny, sdl_event::SDLEventEnum, sys::{self, semver}, *};
+    /// # use mirabel::frontend::*;
+    /// # use std::{ffi::c_void, ptr::null_mut, sync::Mutex};
+    /// /// Options and framebuffer size passed to the last create.
+    /// static CREATED: Mutex<Option<(Option<u8>, (i32, i32))>> = Mutex::new(None);
+    ///
+    /// struct Canvas;
+    ///
+    /// impl FrontendMethods for Canvas {
+    ///     type Options = u8;
+    ///
+    ///     fn create(options: Option<&u8>, display: &frontend_display_data) -> Result<Self> {
+    ///         let size = DisplayData::new(display).framebuffer_size();
+    ///         *CREATED.lock().unwrap() = Some((options.copied(), size));
+    ///         Ok(Self)
+    ///     }
+    ///
+    ///     fn opts_create() -> CodeResult<u8> {
+    ///         Ok(7)
+    ///     }
+    /// #   fn opts_display(_: &mut u8) -> CodeResult<()> { Ok(()) }
+    /// #   fn runtime_opts_display(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+    /// #   fn process_event(&mut self, _: Context<Self>, _: EventAny) -> Result<()> { Ok(()) }
+    /// #   fn process_input(&mut self, _: Context<Self>, _: SDLEventEnum) -> Result<()> { Ok(()) }
+    /// #   fn update(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+    /// #   fn render(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+    /// #   fn is_game_compatible(_: GameInfo) -> CodeResult<()> { Ok(()) }
+    /// }
+    ///
+    /// let methods = create_frontend_methods::<Canvas>(Metadata {
+    ///     frontend_name: cstr("Canvas\0"),
+    ///     version: semver::new(0, 1, 0),
+    ///     features: FrontendFeatures { options: true }.into(),
+    /// });
+    /// let mut display = frontend_display_data {
+    ///     fbw: 640,
+    ///     fbh: 480,
+    ///     ..Default::default()
+    /// };
+    /// let mut frontend = sys::frontend {
+    ///     methods: &methods,
+    ///     ..Default::default()
+    /// };
+    /// let mut options: *mut c_void = null_mut();
+    /// unsafe {
+    ///     methods.opts_create.unwrap()(&mut options);
+    ///     methods.create.unwrap()(&mut frontend, &mut display, options);
+    ///     methods.destroy.unwrap()(&mut frontend);
+    ///     methods.opts_destroy.unwrap()(options);
+    /// }
+    /// assert_eq!(Some((Some(7), (640, 480))), *CREATED.lock().unwrap());
+    /// ```
+    fn create(options: Option<&Self::Options>, display: &frontend_display_data) -> Result<Self>;
     fn runtime_opts_display(&mut self, ctx: Context<Self>) -> Result<()>;
     fn process_event(&mut self, ctx: Context<Self>, event: EventAny) -> Result<()>;
     fn process_input(&mut self, ctx: Context<Self>, event: SDLEventEnum) -> Result<()>;
     fn update(&mut self, ctx: Context<Self>) -> Result<()>;
+    /// _mirabel_ has no dirty flag, so this is called every frame.
+    ///
+    /// _mirabel_ double-buffers, so the whole frame must be drawn every time.
+    /// Frontends with expensive content can instead cache it, call
+    /// [`Context::request_redraw()`] whenever their state changes, and only
+    /// rebuild the cache if [`Context::is_dirty()`].
+    /// The flag is cleared after every successful render.
     fn render(&mut self, ctx: Context<Self>) -> Result<()>;
     fn is_game_compatible(game: GameInfo) -> CodeResult<()>;
 
+    /// Called before [`Self::process_input()`] for window close or quit
+    /// events, see [`SDLEventEnum::is_close_request()`].
+    ///
+    /// Use this to persist state before the frontend gets destroyed.
+    /// This might be called more than once.
+    /// Does nothing by default.
+    fn on_close(&mut self) -> Result<()> {
+        Ok(())
+    }
+
     fn opts_create() -> CodeResult<Self::Options> {
         unimplemented!("opts_create")
     }
@@ -181,13 +280,11 @@ unsafe extern "C" fn create_wrapped<F: FrontendMethods>(
     data1.write(null_mut());
     Aux::<F>::init(frontend, display_data, options_struct);
 
-    // TODO: maybe supply display_data to create
-
-    let data = mirabel_try!(frontend, F::create(options_struct.as_ref()));
+    let data = mirabel_try!(frontend, F::create(options_struct.as_ref(), &*display_data));
     // data1 is already initialized.
     *data1 = Box::into_raw(Box::<F>::new(data)).cast::<c_void>();
 
-    sys::ERR_ERR_FEATURE_UNSUPPORTED
+    ERR_ERR_OK
 }
 
 unsafe extern "C" fn destroy_wrapped<F: FrontendMethods>(
@@ -234,11 +331,21 @@ unsafe extern "C" fn process_input_wrapped<F: FrontendMethods>(
     event: sys::SDL_Event,
 ) -> error_code {
     let event = SDLEventEnum::new(event);
-    #[cfg(feature = "skia")]
+    if event.is_close_request() {
+        mirabel_try!(frontend, F::on_close(get_self(frontend)));
+    }
     if let SDLEventEnum::WindowEvent(event) = event {
-        use crate::sys::SDL_WindowEventID_SDL_WINDOWEVENT_SIZE_CHANGED;
-        if u32::from(event.event) == SDL_WindowEventID_SDL_WINDOWEVENT_SIZE_CHANGED {
-            Aux::<F>::get(frontend).surface = None;
+        let kind = event.kind();
+        let aux = Aux::<F>::get(frontend);
+        if let Some(inside) = kind.pointer_inside() {
+            aux.pointer_inside = inside;
+        }
+        if let crate::sdl_event::WindowEventKind::SizeChanged { .. } = kind {
+            aux.redraw.request();
+            #[cfg(feature = "skia")]
+            {
+                aux.surface = None;
+            }
         }
     }
 
@@ -253,6 +360,7 @@ unsafe extern "C" fn process_input_wrapped<F: FrontendMethods>(
 unsafe extern "C" fn update_wrapped<F: FrontendMethods>(
     frontend: *mut sys::frontend,
 ) -> error_code {
+    Aux::<F>::get(frontend).timer.tick();
     mirabel_try!(
         frontend,
         F::update(get_self(frontend), Context::new(frontend))
@@ -268,6 +376,7 @@ unsafe extern "C" fn render_wrapped<F: FrontendMethods>(
         frontend,
         F::render(get_self(frontend), Context::new(frontend))
     );
+    Aux::<F>::get(frontend).redraw.clear();
     #[cfg(feature = "skia")]
     if let Some(surface) = &mut Aux::<F>::get(frontend).surface {
         surface.flush();
@@ -296,12 +405,21 @@ unsafe fn get_self<'l, F>(frontend: *mut sys::frontend) -> &'l mut F {
 
 /// This provides access to context information and additional tools.
 pub struct Context<'l, F: FrontendMethods> {
-    /// A read-only reference to the pre-create options.
-    pub options: Option<&'l F::Options>,
+    /// The pre-create options.
+    ///
+    /// This is private so that [`Self::options()`] and [`Self::options_mut()`]
+    /// cannot alias.
+    options: Option<&'l mut F::Options>,
     /// Some additional information provided by _mirabel_.
     pub display_data: &'l frontend_display_data,
     /// A helper for sending events to the _mirabel_ core.
     pub outbox: QueueManager<'l>,
+    /// Whether the mouse pointer is inside the window.
+    ///
+    /// This is tracked from the enter and leave window events.
+    pub pointer_inside: bool,
+    timer: FrameTimer,
+    redraw: &'l mut RedrawFlag,
     /// A _Skia_ canvas for drawing the frontend.
     #[cfg(feature = "skia")]
     pub canvas: CanvasManager<'l>,
@@ -315,19 +433,378 @@ impl<'l, F: FrontendMethods + 'l> Context<'l, F> {
         Self {
             // It is ok to use a reference here for options and display_data
             // because 'l does not outlive the wrapper function.
-            options: aux.options.as_ref(),
+            options: aux.options.as_mut(),
             display_data,
             outbox: QueueManager {
                 outbox: display_data.outbox,
                 phantom: Default::default(),
             },
+            pointer_inside: aux.pointer_inside,
+            timer: aux.timer,
+            redraw: &mut aux.redraw,
             #[cfg(feature = "skia")]
             canvas: CanvasManager {
                 surface: &mut aux.surface,
+                config: &mut aux.surface_config,
+                force_raster: &mut aux.force_raster,
                 display_data,
             },
         }
     }
+
+    /// A read-only reference to the pre-create options.
+    ///
+    /// Returns [`None`] if the `options` feature is disabled.
+    #[inline]
+    pub fn options(&self) -> Option<&F::Options> {
+        self.options.as_deref()
+    }
+
+    /// A mutable reference to the pre-create options.
+    ///
+    /// Changes persist for the lifetime of the frontend and are also visible
+    /// in [`FrontendMethods::opts_display()`].
+    /// Returns [`None`] if the `options` feature is disabled.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, event::EventAny, sdl_event::SDLEventEnum, sys::{self, semver}, *};
+    /// # use mirabel::frontend::*;
+    /// # use std::{ffi::c_void, ptr::null_mut};
+    /// /// Counts its updates in the options.
+    /// struct Counter;
+    ///
+    /// impl FrontendMethods for Counter {
+    ///     type Options = u32;
+    ///
+    ///     fn update(&mut self, mut ctx: Context<Self>) -> Result<()> {
+    ///         *ctx.options_mut().unwrap() += 1;
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn opts_create() -> CodeResult<u32> {
+    ///         Ok(0)
+    ///     }
+    /// #   fn opts_display(_: &mut u32) -> CodeResult<()> { Ok(()) }
+    /// #   fn create(_: Option<&u32>, _: &frontend_display_data) -> Result<Self> { Ok(Self) }
+    /// #   fn runtime_opts_display(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+    /// #   fn process_event(&mut self, _: Context<Self>, _: EventAny) -> Result<()> { Ok(()) }
+    /// #   fn process_input(&mut self, _: Context<Self>, _: SDLEventEnum) -> Result<()> { Ok(()) }
+    /// #   fn render(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+    /// #   fn is_game_compatible(_: GameInfo) -> CodeResult<()> { Ok(()) }
+    /// }
+    ///
+    /// let methods = create_frontend_methods::<Counter>(Metadata {
+    ///     frontend_name: cstr("Counter\0"),
+    ///     version: semver::new(0, 1, 0),
+    ///     features: FrontendFeatures { options: true }.into(),
+    /// });
+    /// let mut display = frontend_display_data::default();
+    /// let mut frontend = sys::frontend {
+    ///     methods: &methods,
+    ///     ..Default::default()
+    /// };
+    /// let mut options: *mut c_void = null_mut();
+    /// unsafe {
+    ///     methods.opts_create.unwrap()(&mut options);
+    ///     methods.create.unwrap()(&mut frontend, &mut display, options);
+    ///     for _ in 0..2 {
+    ///         methods.update.unwrap()(&mut frontend);
+    ///     }
+    ///     methods.destroy.unwrap()(&mut frontend);
+    ///
+    ///     // The changes outlive the frontend.
+    ///     assert_eq!(2, *options.cast::<u32>());
+    ///     methods.opts_destroy.unwrap()(options);
+    /// }
+    /// ```
+    #[inline]
+    pub fn options_mut(&mut self) -> Option<&mut F::Options> {
+        self.options.as_deref_mut()
+    }
+
+    /// Time since the frontend was created.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.timer.elapsed()
+    }
+
+    /// Time between the last two [`FrontendMethods::update()`] calls.
+    #[inline]
+    pub fn delta_time(&self) -> Duration {
+        self.timer.delta()
+    }
+
+    /// Mark cached content as outdated, see [`FrontendMethods::render()`].
+    #[inline]
+    pub fn request_redraw(&mut self) {
+        self.redraw.request();
+    }
+
+    /// Whether a redraw was requested since the last render.
+    ///
+    /// This is `true` for the first frame and after the window size changed.
+    #[inline]
+    pub fn is_dirty(&self) -> bool {
+        self.redraw.is_dirty()
+    }
+
+    /// Typed accessors for [`Self::display_data`].
+    #[inline]
+    pub fn display(&self) -> DisplayData<'l> {
+        DisplayData::new(self.display_data)
+    }
+
+    /// See [`DisplayData::map_input_point()`].
+    #[cfg(feature = "skia")]
+    #[inline]
+    pub fn map_input_point(&self, x: i32, y: i32) -> Option<skia::Point> {
+        self.display().map_input_point(x, y)
+    }
+
+    /// See [`DisplayData::map_input_point_f()`].
+    #[cfg(feature = "skia")]
+    #[inline]
+    pub fn map_input_point_f(&self, x: f32, y: f32) -> Option<skia::Point> {
+        self.display().map_input_point_f(x, y)
+    }
+}
+
+/// Typed view of a [`frontend_display_data`].
+///
+/// _mirabel_ assigns the frontend a rectangular play area inside of the
+/// framebuffer.
+/// All coordinates are measured in framebuffer pixels with the origin in the
+/// top left corner of the framebuffer.
+///
+/// # Example
+/// ```
+/// # use mirabel::frontend::*;
+/// let raw = frontend_display_data {
+///     x: 10.,
+///     y: 20.,
+///     w: 300.,
+///     h: 200.,
+///     fbw: 400,
+///     fbh: 300,
+///     ..Default::default()
+/// };
+/// let display = DisplayData::new(&raw);
+/// assert_eq!((400, 300), display.framebuffer_size());
+/// assert_eq!((300., 200.), display.logical_size());
+/// assert_eq!(
+///     skia::Rect::new(10., 20., 310., 220.),
+///     display.play_area_rect()
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct DisplayData<'l>(&'l frontend_display_data);
+
+impl<'l> DisplayData<'l> {
+    #[inline]
+    pub fn new(display_data: &'l frontend_display_data) -> Self {
+        Self(display_data)
+    }
+
+    /// The wrapped [`frontend_display_data`].
+    #[inline]
+    pub fn raw(&self) -> &'l frontend_display_data {
+        self.0
+    }
+
+    /// Size of the whole framebuffer as `(width, height)`.
+    #[inline]
+    pub fn framebuffer_size(&self) -> (i32, i32) {
+        (self.0.fbw as i32, self.0.fbh as i32)
+    }
+
+    /// Size of the play area as `(width, height)`.
+    #[inline]
+    pub fn logical_size(&self) -> (f32, f32) {
+        (self.0.w, self.0.h)
+    }
+
+    /// Background color of _mirabel_'s theme.
+    ///
+    /// [`frontend_display_data`] carries no theme information yet.
+    /// Hence, this is always the light default, but frontends using it will
+    /// follow the theme once _mirabel_ provides one.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::frontend::*;
+    /// let raw = frontend_display_data::default();
+    /// let display = DisplayData::new(&raw);
+    /// assert_eq!(skia::Color4f::new(1., 1., 1., 1.), display.background_color());
+    /// assert_ne!(display.background_color(), display.accent_color());
+    /// ```
+    #[cfg(feature = "skia")]
+    #[inline]
+    pub fn background_color(&self) -> skia::Color4f {
+        skia::Color4f::new(1., 1., 1., 1.)
+    }
+
+    /// Accent color of _mirabel_'s theme, e.g., for highlights.
+    ///
+    /// Like [`Self::background_color()`], this is a fixed default for now.
+    #[cfg(feature = "skia")]
+    #[inline]
+    pub fn accent_color(&self) -> skia::Color4f {
+        skia::Color4f::new(0.26, 0.59, 0.98, 1.)
+    }
+
+    /// The play area inside of the framebuffer.
+    #[cfg(feature = "skia")]
+    #[inline]
+    pub fn play_area_rect(&self) -> skia::Rect {
+        skia::Rect::from_xywh(self.0.x, self.0.y, self.0.w, self.0.h)
+    }
+
+    /// Map a window point (e.g., from an _SDL_ event) to play area
+    /// coordinates.
+    ///
+    /// This inverts [`CanvasManager::matrix()`] and returns [`None`] if this
+    /// is impossible.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::frontend::*;
+    /// let raw = frontend_display_data {
+    ///     x: 10.,
+    ///     y: 20.,
+    ///     ..Default::default()
+    /// };
+    /// let display = DisplayData::new(&raw);
+    /// assert_eq!(Some(skia::Point::new(5., 10.)), display.map_input_point(15, 30));
+    /// ```
+    #[cfg(feature = "skia")]
+    #[inline]
+    pub fn map_input_point(&self, x: i32, y: i32) -> Option<skia::Point> {
+        self.map_input_point_f(x as f32, y as f32)
+    }
+
+    /// Like [`Self::map_input_point()`] but for float inputs.
+    #[cfg(feature = "skia")]
+    pub fn map_input_point_f(&self, x: f32, y: f32) -> Option<skia::Point> {
+        let matrix = skia::Matrix::translate((self.0.x, self.0.y)).invert()?;
+        Some(matrix.map_point((x, y)))
+    }
+}
+
+/// Monotonic clock for animations.
+///
+/// The wrapper keeps one per frontend and exposes it through
+/// [`Context::elapsed()`] and [`Context::delta_time()`].
+///
+/// # Example
+/// ```
+/// # use mirabel::frontend::FrameTimer;
+/// # use std::{thread::sleep, time::Duration};
+/// let mut timer = FrameTimer::new();
+/// assert_eq!(Duration::ZERO, timer.tick());
+/// sleep(Duration::from_millis(5));
+/// let delta = timer.tick();
+/// assert!(delta >= Duration::from_millis(5));
+/// assert_eq!(delta, timer.delta());
+/// assert!(timer.elapsed() >= delta);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FrameTimer {
+    start: Instant,
+    last_tick: Option<Instant>,
+    delta: Duration,
+}
+
+impl FrameTimer {
+    /// Start a new timer.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            last_tick: None,
+            delta: Duration::ZERO,
+        }
+    }
+
+    /// Record a new frame and return the time since the previous one.
+    ///
+    /// The first tick returns zero.
+    pub fn tick(&mut self) -> Duration {
+        let now = Instant::now();
+        self.delta = self.last_tick.map_or(Duration::ZERO, |last| now - last);
+        self.last_tick = Some(now);
+        self.delta
+    }
+
+    /// Time since the timer was started.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Time between the last two [`Self::tick()`]s.
+    #[inline]
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+}
+
+impl Default for FrameTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Frontend-side dirty flag for skipping redundant work while rendering.
+///
+/// Every frontend wrapper owns such a flag, which is cleared after each
+/// render and set on resize.
+/// Frontends access it via [`Context::request_redraw()`] and
+/// [`Context::is_dirty()`].
+///
+/// # Example
+/// ```
+/// # use mirabel::frontend::RedrawFlag;
+/// let mut flag = RedrawFlag::new();
+/// assert!(flag.is_dirty());
+/// flag.clear();
+/// assert!(!flag.is_dirty());
+/// flag.request();
+/// assert!(flag.is_dirty());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RedrawFlag {
+    dirty: bool,
+}
+
+impl RedrawFlag {
+    /// Create a dirty flag so that the first frame is rendered.
+    pub fn new() -> Self {
+        Self { dirty: true }
+    }
+
+    /// Mark as dirty.
+    #[inline]
+    pub fn request(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Mark as rendered.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.dirty = false;
+    }
+
+    /// Whether [`Self::request()`] was called since the last [`Self::clear()`].
+    #[inline]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+}
+
+impl Default for RedrawFlag {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// A wrapper around [`event_queue`] for safely sending events.
@@ -337,6 +814,14 @@ pub struct QueueManager<'l> {
 }
 
 impl<'l> QueueManager<'l> {
+    #[inline]
+    pub(crate) fn new(outbox: &'l mut event_queue) -> Self {
+        Self {
+            outbox,
+            phantom: Default::default(),
+        }
+    }
+
     /// Copy an event to the outbox.
     #[inline]
     pub fn push(&mut self, event: &mut EventAny) {
@@ -344,12 +829,365 @@ impl<'l> QueueManager<'l> {
             sys::event_queue_push(self.outbox, &mut **event);
         }
     }
+
+    /// Send a game move using [`SYNC_CTR_DEFAULT`](sys::SYNC_CTR_DEFAULT).
+    ///
+    /// This is the right choice for perfect-information games.
+    #[inline]
+    pub fn send_move(&mut self, player: player_id, mov: MoveData) {
+        self.send_move_synced(player, mov.with_default_sync());
+    }
+
+    /// Send a game move with an explicit sync counter.
+    ///
+    /// Games with hidden information need the counter of the state the move
+    /// was chosen in.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{event::*, frontend::EventQueue, sys::*, MoveDataSync};
+    /// # use std::{cell::RefCell, collections::VecDeque};
+    /// # // Stand-ins for the event functions of the mirabel host.
+    /// # thread_local! {
+    /// #     static EVENTS: RefCell<VecDeque<event_any>> = RefCell::new(VecDeque::new());
+    /// # }
+    /// # #[no_mangle]
+    /// # extern "C" fn event_queue_create(_: *mut event_queue) {}
+    /// # #[no_mangle]
+    /// # extern "C" fn event_queue_destroy(_: *mut event_queue) {}
+    /// # #[no_mangle]
+    /// # unsafe extern "C" fn event_queue_push(_: *mut event_queue, event: *mut event_any) {
+    /// #     EVENTS.with(|e| e.borrow_mut().push_back(*event));
+    /// # }
+    /// # #[no_mangle]
+    /// # unsafe extern "C" fn event_queue_pop(_: *mut event_queue, event: *mut event_any, _: u32) {
+    /// #     let next = EVENTS.with(|e| e.borrow_mut().pop_front());
+    /// #     event.write(next.unwrap_or_default());
+    /// # }
+    /// # #[no_mangle]
+    /// # extern "C" fn event_destroy(_: *mut event_any) {}
+    /// # #[no_mangle]
+    /// # unsafe extern "C" fn event_create_game_move(
+    /// #     e: *mut event_any,
+    /// #     player: player_id,
+    /// #     data: move_data_sync,
+    /// # ) {
+    /// #     e.write(event_any {
+    /// #         game_move: event_game_move {
+    /// #             base: event {
+    /// #                 type_: EVENT_TYPE_E_EVENT_TYPE_GAME_MOVE,
+    /// #                 ..Default::default()
+    /// #             },
+    /// #             player,
+    /// #             data,
+    /// #             ..Default::default()
+    /// #         },
+    /// #     });
+    /// # }
+    /// fn pop_move(queue: &mut EventQueue) -> (player_id, MoveDataSync<move_code>) {
+    ///     let event = queue.inbox().pop(0).unwrap();
+    ///     let EventEnum::GameMove(event) = event.to_rust() else {
+    ///         panic!("no game move");
+    ///     };
+    ///     let MoveData::MoveCode(code) = event.data.md else {
+    ///         panic!("no move code");
+    ///     };
+    ///     (event.player, MoveDataSync::with_ctr(code, event.data.sync_ctr))
+    /// }
+    ///
+    /// let mut queue = EventQueue::new();
+    /// queue.manager().send_move(2, MoveData::MoveCode(5));
+    /// queue
+    ///     .manager()
+    ///     .send_move_synced(1, MoveDataSync::with_ctr(MoveData::MoveCode(3), 7));
+    ///
+    /// assert_eq!((2, MoveDataSync::with_default(5)), pop_move(&mut queue));
+    /// assert_eq!((1, MoveDataSync::with_ctr(3, 7)), pop_move(&mut queue));
+    /// assert!(queue.inbox().pop(0).is_none());
+    /// ```
+    pub fn send_move_synced(&mut self, player: player_id, mov: MoveDataSync<MoveData>) {
+        self.push(&mut EventAny::new_game_move(player, mov));
+    }
+}
+
+/// A wrapper around [`event_queue`] for safely receiving events.
+pub struct InboxManager<'l> {
+    inbox: *mut event_queue,
+    phantom: PhantomData<&'l mut event_queue>,
+}
+
+impl<'l> InboxManager<'l> {
+    #[inline]
+    pub(crate) fn new(inbox: &'l mut event_queue) -> Self {
+        Self {
+            inbox,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Take the next event out of the inbox.
+    ///
+    /// This waits up to `timeout_ms` milliseconds and returns [`None`] if no
+    /// event arrived in the meantime.
+    pub fn pop(&mut self, timeout_ms: u32) -> Option<EventAny> {
+        let mut event = MaybeUninit::<event_any>::uninit();
+        let event = unsafe {
+            sys::event_queue_pop(self.inbox, event.as_mut_ptr(), timeout_ms);
+            EventAny::new(event.assume_init())
+        };
+        if event.get_type() == sys::EVENT_TYPE_E_EVENT_TYPE_NULL {
+            // Null events own nothing.
+            forget(event);
+            return None;
+        }
+        Some(event)
+    }
+}
+
+/// Owned [`event_queue`] for buffering events.
+///
+/// This is mainly useful for tests and tools which need a queue outside of
+/// _mirabel_.
+/// Dropping the queue also destroys all remaining events.
+///
+/// # Example
+/// ```
+/// # use mirabel::{event::EventAny, frontend::EventQueue, sys::*};
+/// # use std::{cell::{Cell, RefCell}, collections::VecDeque};
+/// # // Stand-ins for the queue functions of the mirabel host.
+/// # thread_local! {
+/// #     static EVENTS: RefCell<VecDeque<event_any>> = RefCell::new(VecDeque::new());
+/// #     static DESTROYED: Cell<bool> = Cell::new(false);
+/// # }
+/// # #[no_mangle]
+/// # extern "C" fn event_queue_create(_: *mut event_queue) {}
+/// # #[no_mangle]
+/// # extern "C" fn event_queue_destroy(_: *mut event_queue) {
+/// #     DESTROYED.with(|d| d.set(true));
+/// # }
+/// # #[no_mangle]
+/// # unsafe extern "C" fn event_queue_push(_: *mut event_queue, event: *mut event_any) {
+/// #     EVENTS.with(|e| e.borrow_mut().push_back(*event));
+/// # }
+/// # #[no_mangle]
+/// # unsafe extern "C" fn event_queue_pop(_: *mut event_queue, event: *mut event_any, _: u32) {
+/// #     let next = EVENTS.with(|e| e.borrow_mut().pop_front());
+/// #     event.write(next.unwrap_or_default());
+/// # }
+/// # #[no_mangle]
+/// # extern "C" fn event_destroy(_: *mut event_any) {}
+/// let mut queue = EventQueue::new();
+/// let mut unload = unsafe {
+///     EventAny::new(event_any {
+///         base: event {
+///             type_: EVENT_TYPE_E_EVENT_TYPE_GAME_UNLOAD,
+///             ..Default::default()
+///         },
+///     })
+/// };
+/// queue.manager().push(&mut unload);
+/// let popped = queue.inbox().pop(0).unwrap();
+/// assert_eq!(EVENT_TYPE_E_EVENT_TYPE_GAME_UNLOAD, popped.get_type());
+/// assert!(queue.inbox().pop(0).is_none());
+///
+/// drop(queue);
+/// assert!(DESTROYED.with(Cell::get));
+/// ```
+pub struct EventQueue(Box<event_queue>);
+
+impl EventQueue {
+    /// Create a new, empty queue.
+    pub fn new() -> Self {
+        let queue = Box::into_raw(Box::new(MaybeUninit::<event_queue>::uninit()));
+        unsafe {
+            // The queue is pinned by the box because it contains a mutex.
+            sys::event_queue_create((*queue).as_mut_ptr());
+            Self(Box::from_raw(queue.cast()))
+        }
+    }
+
+    /// Return a [`QueueManager`] for pushing events into this queue.
+    #[inline]
+    pub fn manager(&mut self) -> QueueManager {
+        QueueManager::new(&mut self.0)
+    }
+
+    /// Return an [`InboxManager`] for taking events out of this queue.
+    #[inline]
+    pub fn inbox(&mut self) -> InboxManager {
+        InboxManager::new(&mut self.0)
+    }
+}
+
+impl Default for EventQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for EventQueue {
+    fn drop(&mut self) {
+        unsafe { sys::event_queue_destroy(&mut *self.0) };
+    }
+}
+
+/// Return a stable color for `player`.
+///
+/// Players `1..=8` get distinct colors from a fixed palette: red, blue,
+/// green, orange, purple, cyan, brown, and pink.
+/// Higher ids cycle through the palette again.
+/// [`PLAYER_NONE`](sys::PLAYER_NONE) is gray and
+/// [`PLAYER_RAND`](sys::PLAYER_RAND) is black.
+///
+/// # Example
+/// ```
+/// # use mirabel::{frontend::player_color, game::PLAYER_NONE};
+/// assert_ne!(player_color(1), player_color(2));
+/// assert_eq!(player_color(1), player_color(1));
+/// assert_eq!(player_color(1), player_color(9));
+/// assert_ne!(player_color(PLAYER_NONE), player_color(1));
+/// ```
+#[cfg(feature = "skia")]
+pub fn player_color(player: player_id) -> skia::Color4f {
+    const PALETTE: [(f32, f32, f32); 8] = [
+        (0.89, 0.10, 0.11),
+        (0.22, 0.49, 0.72),
+        (0.30, 0.69, 0.29),
+        (1.00, 0.50, 0.00),
+        (0.60, 0.31, 0.64),
+        (0.10, 0.75, 0.81),
+        (0.65, 0.34, 0.16),
+        (0.97, 0.51, 0.75),
+    ];
+    let (r, g, b) = match player {
+        sys::PLAYER_NONE => (0.5, 0.5, 0.5),
+        sys::PLAYER_RAND => (0., 0., 0.),
+        player => PALETTE[usize::from(player - 1) % PALETTE.len()],
+    };
+    skia::Color4f::new(r, g, b, 1.)
+}
+
+/// Pixel format of the framebuffer _Skia_ draws into.
+///
+/// This must match the framebuffer of _mirabel_.
+/// The default is plain RGBA8 without sRGB encoding.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::*, event::EventAny, sdl_event::SDLEventEnum, sys::{self, semver}, *};
+/// # use mirabel::frontend::{skia::ColorType, *};
+/// # use std::{ptr::null_mut, sync::Mutex};
+/// /// Color type and sRGB tagging of the surface per rendered frame.
+/// static SURFACES: Mutex<Vec<(ColorType, bool)>> = Mutex::new(Vec::new());
+///
+/// /// Switches to BGRA with sRGB after the first frame.
+/// struct Board {
+///     frame: usize,
+/// }
+///
+/// impl FrontendMethods for Board {
+///     type Options = ();
+///
+///     fn render(&mut self, mut ctx: Context<Self>) -> Result<()> {
+///         ctx.canvas.force_raster(true);
+///         if self.frame > 0 {
+///             ctx.canvas.set_config(SurfaceConfig {
+///                 color_type: ColorType::BGRA8888,
+///                 srgb: true,
+///                 ..Default::default()
+///             });
+///         }
+///         self.frame += 1;
+///
+///         let info = ctx.canvas.get().image_info();
+///         let srgb = info.color_space().map_or(false, |c| c.is_srgb());
+///         SURFACES.lock().unwrap().push((info.color_type(), srgb));
+///         Ok(())
+///     }
+/// #   fn create(_: Option<&()>, _: &frontend_display_data) -> Result<Self> { Ok(Self { frame: 0 }) }
+/// #   fn runtime_opts_display(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+/// #   fn process_event(&mut self, _: Context<Self>, _: EventAny) -> Result<()> { Ok(()) }
+/// #   fn process_input(&mut self, _: Context<Self>, _: SDLEventEnum) -> Result<()> { Ok(()) }
+/// #   fn update(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+/// #   fn is_game_compatible(_: GameInfo) -> CodeResult<()> { Ok(()) }
+/// }
+///
+/// let methods = create_frontend_methods::<Board>(Metadata {
+///     frontend_name: cstr("Board\0"),
+///     version: semver::new(0, 1, 0),
+///     features: FrontendFeatures::default().into(),
+/// });
+/// let mut display = frontend_display_data {
+///     w: 100.,
+///     h: 60.,
+///     fbw: 100,
+///     fbh: 60,
+///     ..Default::default()
+/// };
+/// let mut frontend = sys::frontend {
+///     methods: &methods,
+///     ..Default::default()
+/// };
+/// unsafe {
+///     methods.create.unwrap()(&mut frontend, &mut display, null_mut());
+///     for _ in 0..3 {
+///         assert_eq!(sys::ERR_ERR_OK, methods.render.unwrap()(&mut frontend));
+///     }
+///     methods.destroy.unwrap()(&mut frontend);
+/// }
+///
+/// let surfaces = SURFACES.lock().unwrap();
+/// assert_eq!((ColorType::RGBA8888, false), surfaces[0]);
+/// // The surface is recreated with the new configuration.
+/// assert_eq!((ColorType::BGRA8888, true), surfaces[1]);
+/// // Setting the same configuration again keeps the surface.
+/// assert_eq!((ColorType::BGRA8888, true), surfaces[2]);
+/// ```
+#[cfg(feature = "skia")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SurfaceConfig {
+    pub color_type: skia::ColorType,
+    pub format: skia::gpu::gl::Format,
+    /// Tag the surface with an sRGB color space.
+    pub srgb: bool,
+}
+
+#[cfg(feature = "skia")]
+impl SurfaceConfig {
+    /// Detect the configuration of the currently bound framebuffer.
+    ///
+    /// This requires a current _OpenGL_ context, e.g., in
+    /// [`FrontendMethods::render()`].
+    pub fn detect() -> Self {
+        if skia_helper::framebuffer_is_srgb() {
+            Self {
+                color_type: skia::ColorType::SRGBA8888,
+                format: skia::gpu::gl::Format::SRGB8_ALPHA8,
+                srgb: true,
+            }
+        } else {
+            Self::default()
+        }
+    }
+}
+
+#[cfg(feature = "skia")]
+impl Default for SurfaceConfig {
+    fn default() -> Self {
+        Self {
+            color_type: skia::ColorType::RGBA8888,
+            format: skia::gpu::gl::Format::RGBA8,
+            srgb: false,
+        }
+    }
 }
 
 /// A wrapper around [`skia::Surface`] for lazy creation of a [`skia::Canvas`].
 #[cfg(feature = "skia")]
 pub struct CanvasManager<'l> {
     surface: &'l mut Option<skia::Surface>,
+    config: &'l mut SurfaceConfig,
+    force_raster: &'l mut bool,
     pub display_data: &'l frontend_display_data,
 }
 
@@ -358,18 +1196,59 @@ impl<'l> CanvasManager<'l> {
     /// Create a new [`skia::Canvas`] for drawing on it.
     ///
     /// This also adjusts the origin to the visible area.
+    ///
+    /// If no _OpenGL_ context is current, this falls back to drawing into
+    /// main memory and logs a warning once.
     #[must_use]
     pub fn get(&mut self) -> &mut skia::Canvas {
         self.surface
             .get_or_insert_with(|| {
-                skia_helper::create_surface(
-                    self.display_data.fbw as i32,
-                    self.display_data.fbh as i32,
-                )
+                let (width, height) = DisplayData::new(self.display_data).framebuffer_size();
+                if *self.force_raster {
+                    return skia_helper::create_raster_surface(width, height, self.config);
+                }
+                if !skia_helper::has_gl_context() {
+                    crate::log::log_once(
+                        crate::log::LogLevel::Warn,
+                        "raster fallback",
+                        "no OpenGL context, falling back to raster surface",
+                    );
+                    return skia_helper::create_raster_surface(width, height, self.config);
+                }
+                skia_helper::create_surface(width, height, self.config)
             })
             .canvas()
     }
 
+    /// Always draw into main memory instead of the _OpenGL_ framebuffer.
+    ///
+    /// This is useful for screenshot tests without an _OpenGL_ context.
+    /// The surface is recreated on the next [`Self::get()`] if the mode
+    /// changed.
+    pub fn force_raster(&mut self, force: bool) {
+        if *self.force_raster != force {
+            *self.force_raster = force;
+            *self.surface = None;
+        }
+    }
+
+    /// Return the configuration used for creating the surface.
+    #[inline]
+    pub fn config(&self) -> &SurfaceConfig {
+        self.config
+    }
+
+    /// Change the configuration used for creating the surface.
+    ///
+    /// The surface is recreated on the next [`Self::get()`] if the
+    /// configuration changed.
+    pub fn set_config(&mut self, config: SurfaceConfig) {
+        if *self.config != config {
+            *self.config = config;
+            *self.surface = None;
+        }
+    }
+
     /// Returns a translation matrix.
     ///
     /// The returned matrix sets the origin of the frame to the top left of the
@@ -380,11 +1259,330 @@ impl<'l> CanvasManager<'l> {
     pub fn matrix(&self) -> skia::Matrix {
         skia::Matrix::translate((self.display_data.x, self.display_data.y))
     }
+
+    /// Prepare the canvas for drawing a new frame.
+    ///
+    /// This sets the origin to the play area using [`Self::matrix()`] and
+    /// clears the whole canvas with `clear`.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, event::EventAny, sdl_event::SDLEventEnum, sys::{self, semver}, *};
+    /// # use mirabel::frontend::{skia::{Color, Color4f, Paint, Rect}, *};
+    /// # use std::{ptr::null_mut, sync::Mutex};
+    /// /// Colors of the framebuffer corner, the play area corner, and outside
+    /// /// of the rectangle per rendered frame.
+    /// static PIXELS: Mutex<Vec<[Color; 3]>> = Mutex::new(Vec::new());
+    ///
+    /// /// Clears with a different color in every frame.
+    /// struct Board {
+    ///     frame: usize,
+    /// }
+    ///
+    /// impl FrontendMethods for Board {
+    ///     type Options = ();
+    ///
+    ///     fn render(&mut self, mut ctx: Context<Self>) -> Result<()> {
+    ///         ctx.canvas.force_raster(true);
+    ///         let clear = [Color::RED, Color::GREEN][self.frame];
+    ///         let canvas = ctx.canvas.begin_frame(Color4f::from(clear));
+    ///         // Relative to the play area.
+    ///         let paint = Paint::new(Color4f::from(Color::BLUE), None);
+    ///         canvas.draw_rect(Rect::new(0., 0., 10., 10.), &paint);
+    ///         ctx.canvas.end_frame();
+    ///         self.frame += 1;
+    ///
+    ///         let pixels = ctx.canvas.get().peek_pixels().unwrap();
+    ///         PIXELS.lock().unwrap().push([
+    ///             pixels.get_color((0, 0)),
+    ///             pixels.get_color((55, 25)),
+    ///             pixels.get_color((65, 35)),
+    ///         ]);
+    ///         Ok(())
+    ///     }
+    /// #   fn create(_: Option<&()>, _: &frontend_display_data) -> Result<Self> { Ok(Self { frame: 0 }) }
+    /// #   fn runtime_opts_display(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+    /// #   fn process_event(&mut self, _: Context<Self>, _: EventAny) -> Result<()> { Ok(()) }
+    /// #   fn process_input(&mut self, _: Context<Self>, _: SDLEventEnum) -> Result<()> { Ok(()) }
+    /// #   fn update(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+    /// #   fn is_game_compatible(_: GameInfo) -> CodeResult<()> { Ok(()) }
+    /// }
+    ///
+    /// let methods = create_frontend_methods::<Board>(Metadata {
+    ///     frontend_name: cstr("Board\0"),
+    ///     version: semver::new(0, 1, 0),
+    ///     features: FrontendFeatures::default().into(),
+    /// });
+    /// let mut display = frontend_display_data {
+    ///     x: 50.,
+    ///     y: 20.,
+    ///     w: 100.,
+    ///     h: 60.,
+    ///     fbw: 200,
+    ///     fbh: 100,
+    ///     ..Default::default()
+    /// };
+    /// let mut frontend = sys::frontend {
+    ///     methods: &methods,
+    ///     ..Default::default()
+    /// };
+    /// unsafe {
+    ///     methods.create.unwrap()(&mut frontend, &mut display, null_mut());
+    ///     for _ in 0..2 {
+    ///         assert_eq!(sys::ERR_ERR_OK, methods.render.unwrap()(&mut frontend));
+    ///     }
+    ///     methods.destroy.unwrap()(&mut frontend);
+    /// }
+    ///
+    /// // The whole framebuffer is cleared, also outside of the play area.
+    /// let pixels = PIXELS.lock().unwrap();
+    /// assert_eq!([Color::RED, Color::BLUE, Color::RED], pixels[0]);
+    /// // Nothing is left over from the previous frame.
+    /// assert_eq!([Color::GREEN, Color::BLUE, Color::GREEN], pixels[1]);
+    /// ```
+    pub fn begin_frame(&mut self, clear: skia::Color4f) -> &mut skia::Canvas {
+        let matrix = self.matrix();
+        let canvas = self.get();
+        canvas.set_matrix(&matrix.into());
+        canvas.clear(clear);
+        canvas
+    }
+
+    /// Draw `text` centered in the play area.
+    ///
+    /// This works independently of the current matrix of the canvas.
+    /// Empty strings draw nothing.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, event::EventAny, sdl_event::SDLEventEnum, sys::{self, semver}, *};
+    /// # use mirabel::frontend::{skia::{Color, Color4f, Font, Paint, Pixmap}, *};
+    /// # use std::{ptr::null_mut, sync::Mutex};
+    /// /// Center of the non-white pixels per rendered frame.
+    /// static DRAWN: Mutex<Vec<Option<(i32, i32)>>> = Mutex::new(Vec::new());
+    ///
+    /// fn drawn_center(pixels: &Pixmap) -> Option<(i32, i32)> {
+    ///     let (mut min, mut max) = ((i32::MAX, i32::MAX), (i32::MIN, i32::MIN));
+    ///     for y in 0..pixels.height() {
+    ///         for x in 0..pixels.width() {
+    ///             if pixels.get_color((x, y)) != Color::WHITE {
+    ///                 min = (min.0.min(x), min.1.min(y));
+    ///                 max = (max.0.max(x), max.1.max(y));
+    ///             }
+    ///         }
+    ///     }
+    ///     (min.0 <= max.0).then(|| ((min.0 + max.0) / 2, (min.1 + max.1) / 2))
+    /// }
+    ///
+    /// /// Draws "X" in the first frame and nothing in the second.
+    /// struct Title {
+    ///     frame: usize,
+    /// }
+    ///
+    /// impl FrontendMethods for Title {
+    ///     type Options = ();
+    ///
+    ///     fn render(&mut self, mut ctx: Context<Self>) -> Result<()> {
+    ///         ctx.canvas.force_raster(true);
+    ///         ctx.canvas.begin_frame(Color4f::from(Color::WHITE));
+    ///         let paint = Paint::new(Color4f::from(Color::BLACK), None);
+    ///         let text = ["X", ""][self.frame];
+    ///         ctx.canvas.draw_centered_text(text, &Font::default(), &paint);
+    ///         self.frame += 1;
+    ///
+    ///         let pixels = ctx.canvas.get().peek_pixels().unwrap();
+    ///         DRAWN.lock().unwrap().push(drawn_center(&pixels));
+    ///         Ok(())
+    ///     }
+    /// #   fn create(_: Option<&()>, _: &frontend_display_data) -> Result<Self> { Ok(Self { frame: 0 }) }
+    /// #   fn runtime_opts_display(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+    /// #   fn process_event(&mut self, _: Context<Self>, _: EventAny) -> Result<()> { Ok(()) }
+    /// #   fn process_input(&mut self, _: Context<Self>, _: SDLEventEnum) -> Result<()> { Ok(()) }
+    /// #   fn update(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+    /// #   fn is_game_compatible(_: GameInfo) -> CodeResult<()> { Ok(()) }
+    /// }
+    ///
+    /// let methods = create_frontend_methods::<Title>(Metadata {
+    ///     frontend_name: cstr("Title\0"),
+    ///     version: semver::new(0, 1, 0),
+    ///     features: FrontendFeatures::default().into(),
+    /// });
+    /// let mut display = frontend_display_data {
+    ///     x: 50.,
+    ///     y: 20.,
+    ///     w: 100.,
+    ///     h: 60.,
+    ///     fbw: 200,
+    ///     fbh: 100,
+    ///     ..Default::default()
+    /// };
+    /// let mut frontend = sys::frontend {
+    ///     methods: &methods,
+    ///     ..Default::default()
+    /// };
+    /// unsafe {
+    ///     methods.create.unwrap()(&mut frontend, &mut display, null_mut());
+    ///     for _ in 0..2 {
+    ///         assert_eq!(sys::ERR_ERR_OK, methods.render.unwrap()(&mut frontend));
+    ///     }
+    ///     methods.destroy.unwrap()(&mut frontend);
+    /// }
+    ///
+    /// let drawn = DRAWN.lock().unwrap();
+    /// // The text lands in the center of the play area, not of the framebuffer.
+    /// let (x, y) = drawn[0].expect("text drawn");
+    /// assert!((x - 100).abs() <= 2);
+    /// assert!((y - 50).abs() <= 2);
+    /// assert_eq!(None, drawn[1]);
+    /// ```
+    pub fn draw_centered_text(&mut self, text: &str, font: &skia::Font, paint: &skia::Paint) {
+        if text.is_empty() {
+            return;
+        }
+        let (_, bounds) = font.measure_str(text, Some(paint));
+        let center = DisplayData::new(self.display_data)
+            .play_area_rect()
+            .center();
+        let origin = center - bounds.center();
+
+        let canvas = self.get();
+        canvas.save();
+        canvas.reset_matrix();
+        canvas.draw_str(text, origin, font, paint);
+        canvas.restore();
+    }
+
+    /// Flush the drawing commands of this frame.
+    ///
+    /// The wrapper also flushes after [`FrontendMethods::render()`], so this
+    /// is only needed when flushing early.
+    pub fn end_frame(&mut self) {
+        if let Some(surface) = self.surface.as_mut() {
+            surface.flush();
+        }
+    }
+
+    /// Submit pending _Skia_ commands before issuing raw [`gl`] calls.
+    ///
+    /// Must be followed by [`Self::end_raw_gl()`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use mirabel::frontend::{gl, skia, CanvasManager};
+    /// fn draw(canvas: &mut CanvasManager) {
+    ///     canvas.begin_raw_gl();
+    ///     unsafe {
+    ///         gl::ClearColor(0.2, 0.2, 0.2, 1.);
+    ///         gl::Clear(gl::COLOR_BUFFER_BIT);
+    ///     }
+    ///     canvas.end_raw_gl();
+    ///     let paint = skia::Paint::new(skia::Color4f::new(1., 0., 0., 1.), None);
+    ///     canvas.get().draw_circle((50., 50.), 20., &paint);
+    /// }
+    /// ```
+    pub fn begin_raw_gl(&mut self) {
+        if let Some(surface) = self.surface.as_mut() {
+            surface.flush_and_submit();
+        }
+    }
+
+    /// Tell _Skia_ that raw [`gl`] calls may have changed the _OpenGL_ state.
+    ///
+    /// _Skia_ then rebinds its own state before drawing again.
+    pub fn end_raw_gl(&mut self) {
+        if let Some(mut context) = self.surface.as_mut().and_then(|s| s.direct_context()) {
+            context.reset(None);
+        }
+    }
+}
+
+/// Remembers game states for detecting changes, e.g., to start animations.
+///
+/// Feed it the state strings from [`EventGameState`] in
+/// [`FrontendMethods::process_event()`].
+/// The format of the strings does not matter.
+///
+/// # Example
+/// ```
+/// # use mirabel::frontend::StateTracker;
+/// let mut tracker = StateTracker::default();
+/// assert!(tracker.changed("7"));
+/// assert!(!tracker.changed("7"));
+/// assert!(tracker.changed("5"));
+/// assert_eq!(Some("7"), tracker.previous());
+/// assert_eq!(Some("5"), tracker.current());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StateTracker {
+    current: Option<String>,
+    previous: Option<String>,
+}
+
+impl StateTracker {
+    /// Record `new` and return whether it differs from the current state.
+    ///
+    /// On a change, the current state becomes [`Self::previous()`].
+    pub fn changed(&mut self, new: &str) -> bool {
+        if self.current.as_deref() == Some(new) {
+            return false;
+        }
+        let old = self.previous.take();
+        self.previous = self.current.take();
+        // Reuse the allocation of the state before the previous one.
+        let mut current = old.unwrap_or_default();
+        current.clear();
+        current.push_str(new);
+        self.current = Some(current);
+        true
+    }
+
+    /// The state before the last change.
+    #[inline]
+    pub fn previous(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    /// The last recorded state.
+    #[inline]
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// Forget all states, e.g., when a new game is loaded.
+    pub fn reset(&mut self) {
+        self.current = None;
+        self.previous = None;
+    }
 }
 
 /// Basic information about a game.
 ///
 /// This is derived from the [`game_methods`](sys::game_methods).
+///
+/// # Example
+/// The helpers can be combined for implementing
+/// [`FrontendMethods::is_game_compatible()`]:
+/// ```
+/// # use mirabel::{error::*, frontend::*, sys::{game_feature_flags, semver}, CodeResult};
+/// fn is_game_compatible(game: GameInfo) -> CodeResult<()> {
+///     (game.name_matches("go")
+///         && game.version_at_least(semver::new(0, 2, 0))
+///         && !game.requires_feature(game_feature_flags::random_moves))
+///     .then_some(())
+///     .ok_or(ErrorCode::FeatureUnsupported)
+/// }
+///
+/// let chess = GameInfo {
+///     game_name: "Chess",
+///     variant_name: "Standard",
+///     impl_name: "mirabel_rs",
+///     version: semver::new(1, 0, 0),
+///     features: Default::default(),
+/// };
+/// assert!(!chess.name_matches("go"));
+/// assert!(chess.name_matches("chess"));
+/// assert!(is_game_compatible(chess).is_err());
+/// ```
 pub struct GameInfo<'l> {
     pub game_name: &'l str,
     pub variant_name: &'l str,
@@ -404,26 +1602,278 @@ impl<'l> GameInfo<'l> {
             features: *addr_of!((*methods).features),
         }
     }
+
+    /// Check a feature flag of the game.
+    ///
+    /// `f` is usually a getter like [`game_feature_flags::print`].
+    #[inline]
+    pub fn requires_feature(&self, f: fn(&game_feature_flags) -> bool) -> bool {
+        f(&self.features)
+    }
+
+    /// Compare the game name case-insensitively.
+    #[inline]
+    pub fn name_matches(&self, name: &str) -> bool {
+        self.game_name.eq_ignore_ascii_case(name)
+    }
+
+    /// Check that the game has at least version `v`.
+    #[inline]
+    pub fn version_at_least(&self, v: semver) -> bool {
+        self.version >= v
+    }
 }
 
-/// Non-function members for [`frontend_methods`].
+/// An instance of a game created from its [`game_methods`](sys::game_methods).
+///
+/// This allows frontends to query the actual game, e.g., for highlighting
+/// legal moves.
+/// The game is destroyed on drop.
 ///
 /// # Example
 /// ```
-/// # use mirabel::{cstr, sys::semver, frontend::*};
-/// use std::ffi::CStr;
+/// # use mirabel::{error::*, event::MoveData, frontend::LoadedGame, game::*, game_init::GameInit, *};
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// static DROPPED: AtomicBool = AtomicBool::new(false);
 ///
-/// let mut features = frontend_feature_flags::default();
-/// features.set_options(true);
+/// #[derive(Clone, PartialEq, Eq)]
+/// struct Countdown(u64);
 ///
+/// impl SimpleGame for Countdown {
+///     fn create(_: &GameInit) -> Result<Self> {
+///         Ok(Self(2))
+///     }
+///     fn players_to_move(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+///         players.push(1);
+///         Ok(())
+///     }
+///     fn get_concrete_moves(&mut self, _: player_id, moves: &mut Vec<MoveCode>) -> Result<()> {
+///         moves.extend_codes(1..=self.0);
+///         Ok(())
+///     }
+/// #   fn player_count(&mut self) -> Result<u8> { Ok(1) }
+/// #   fn import_state(&mut self, _: Option<&str>) -> Result<()> { Ok(()) }
+/// #   fn export_state(&mut self, _: player_id, _: &mut ValidCString) -> Result<()> { Ok(()) }
+/// #   fn get_move_data(&mut self, _: player_id, _: &str) -> Result<move_code> { Ok(0) }
+/// #   fn get_move_str(&mut self, _: player_id, _: move_code, _: &mut ValidCString) -> Result<()> { Ok(()) }
+/// #   fn make_move(&mut self, _: player_id, _: move_code) -> Result<()> { Ok(()) }
+/// #   fn get_results(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+/// #   fn is_legal_move(&mut self, _: player_id, _: move_code) -> Result<()> { Ok(()) }
+/// }
+///
+/// impl Drop for Countdown {
+///     fn drop(&mut self) {
+///         DROPPED.store(true, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let methods = create_game_methods::<Countdown>(
+///     Metadata::builder()
+///         .game_name("Countdown")
+///         .variant_name("Standard")
+///         .impl_name("mirabel_rs")
+///         .build(),
+/// );
+/// let mut game = unsafe { LoadedGame::new_default(&methods).unwrap() };
+/// assert_eq!("Countdown", game.info().game_name);
+/// assert_eq!(&[1], game.players_to_move().unwrap());
+/// assert_eq!(
+///     vec![MoveData::MoveCode(1), MoveData::MoveCode(2)],
+///     game.get_concrete_moves(1).unwrap()
+/// );
+///
+/// drop(game);
+/// assert!(DROPPED.load(Ordering::Relaxed));
+/// ```
+pub struct LoadedGame {
+    /// Boxed so that the game never moves.
+    game: Box<sys::game>,
+}
+
+impl LoadedGame {
+    /// Create a new game using `init_info`.
+    ///
+    /// # Safety
+    /// `methods` must be valid and outlive the returned game.
+    /// `init_info` must be valid.
+    pub unsafe fn new(
+        methods: *const sys::game_methods,
+        init_info: &mut sys::game_init,
+    ) -> CodeResult<Self> {
+        let mut game = Box::new(sys::game {
+            methods,
+            ..Default::default()
+        });
+        let create = (*methods).create.expect("create missing");
+        if let Err(code) = code_to_result(create(&mut *game, init_info)) {
+            // The game must be destroyed even if creation failed.
+            drop(Self { game });
+            return Err(code);
+        }
+        Ok(Self { game })
+    }
+
+    /// Create a new game with default options and state.
+    ///
+    /// # Safety
+    /// `methods` must be valid and outlive the returned game.
+    pub unsafe fn new_default(methods: *const sys::game_methods) -> CodeResult<Self> {
+        let mut init_info = sys::game_init {
+            source_type: sys::GAME_INIT_SOURCE_TYPE_E_GAME_INIT_SOURCE_TYPE_DEFAULT,
+            ..Default::default()
+        };
+        Self::new(methods, &mut init_info)
+    }
+
+    /// Metadata of the game.
+    #[inline]
+    pub fn info(&self) -> GameInfo {
+        unsafe { GameInfo::new(self.game.methods) }
+    }
+
+    /// Raw access to the game instance.
+    #[inline]
+    pub fn raw(&mut self) -> *mut sys::game {
+        &mut *self.game
+    }
+
+    /// Return the players which are to move.
+    pub fn players_to_move(&mut self) -> CodeResult<&[player_id]> {
+        let mut count = 0;
+        let mut players = null();
+        unsafe {
+            let function = (*self.game.methods)
+                .players_to_move
+                .expect("players_to_move missing");
+            code_to_result(function(&mut *self.game, &mut count, &mut players))?;
+            Ok(slice_from_raw_hedged(players, count.into()))
+        }
+    }
+
+    /// Check whether multiple players are to move at once.
+    ///
+    /// _surena_ has no feature flag for this and [`GameInfo`] cannot reach the
+    /// game methods.
+    /// Hence, this queries the players to move in the current state.
+    /// For checking the initial state, create a throwaway game with
+    /// [`Self::new_default()`], which costs a full create and destroy.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, frontend::LoadedGame, game::*, game_init::GameInit, testing::Countdown, *};
+    /// /// Game in which both players move at once.
+    /// #[derive(Clone, PartialEq, Eq)]
+    /// struct Simultaneous;
+    ///
+    /// impl SimpleGame for Simultaneous {
+    ///     fn players_to_move(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+    ///         players.extend([1, 2]);
+    ///         Ok(())
+    ///     }
+    /// #   fn create(_: &GameInit) -> Result<Self> { Ok(Self) }
+    /// #   fn player_count(&mut self) -> Result<u8> { Ok(2) }
+    /// #   fn import_state(&mut self, _: Option<&str>) -> Result<()> { Ok(()) }
+    /// #   fn export_state(&mut self, _: player_id, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn get_concrete_moves(&mut self, _: player_id, _: &mut Vec<MoveCode>) -> Result<()> { Ok(()) }
+    /// #   fn get_move_data(&mut self, _: player_id, _: &str) -> Result<move_code> { Ok(0) }
+    /// #   fn get_move_str(&mut self, _: player_id, _: move_code, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn make_move(&mut self, _: player_id, _: move_code) -> Result<()> { Ok(()) }
+    /// #   fn get_results(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn is_legal_move(&mut self, _: player_id, _: move_code) -> Result<()> { Ok(()) }
+    /// }
+    ///
+    /// /// Probe the initial state with a throwaway game.
+    /// fn is_simultaneous<G: GameMethods>() -> bool {
+    ///     let methods = create_game_methods::<G>(
+    ///         Metadata::builder()
+    ///             .game_name("Probe")
+    ///             .variant_name("Standard")
+    ///             .impl_name("mirabel_rs")
+    ///             .build(),
+    ///     );
+    ///     let mut game = unsafe { LoadedGame::new_default(&methods).unwrap() };
+    ///     game.is_simultaneous().unwrap()
+    /// }
+    ///
+    /// assert!(!is_simultaneous::<Countdown>());
+    /// assert!(is_simultaneous::<Simultaneous>());
+    /// ```
+    pub fn is_simultaneous(&mut self) -> CodeResult<bool> {
+        Ok(self.players_to_move()?.len() > 1)
+    }
+
+    /// Return the legal moves of `player`.
+    ///
+    /// The moves borrow from the game until it is used again.
+    pub fn get_concrete_moves(&mut self, player: player_id) -> CodeResult<Vec<MoveData>> {
+        let mut count = 0;
+        let mut moves = null();
+        unsafe {
+            let function = (*self.game.methods)
+                .get_concrete_moves
+                .expect("get_concrete_moves missing");
+            code_to_result(function(&mut *self.game, player, &mut count, &mut moves))?;
+            let moves = slice_from_raw_hedged(moves, count.try_into().unwrap());
+            Ok(moves.iter().map(|m| MoveData::from_ref(m)).collect())
+        }
+    }
+}
+
+impl Drop for LoadedGame {
+    fn drop(&mut self) {
+        unsafe {
+            if let Some(destroy) = (*self.game.methods).destroy {
+                destroy(&mut *self.game);
+            }
+        }
+    }
+}
+
+/// Optional frontend features which are supported by this wrapper.
+///
+/// Declarative alternative to setting [`frontend_feature_flags`] by hand.
+///
+/// # Example
+/// ```
+/// # use mirabel::frontend::*;
+/// let mut flags = frontend_feature_flags::default();
+/// flags.set_options(true);
+///
+/// let features = FrontendFeatures { options: true };
+/// assert_eq!(flags, features.feature_flags());
+/// ```
+#[derive(Default, PartialEq, Eq, Debug)]
+pub struct FrontendFeatures {
+    pub options: bool,
+}
+
+impl FrontendFeatures {
+    /// Convert into the raw flags for [`Metadata::features`].
+    #[inline]
+    pub fn feature_flags(&self) -> frontend_feature_flags {
+        let mut flags = frontend_feature_flags::default();
+        flags.set_options(self.options);
+        flags
+    }
+}
+
+impl From<FrontendFeatures> for frontend_feature_flags {
+    #[inline]
+    fn from(value: FrontendFeatures) -> Self {
+        value.feature_flags()
+    }
+}
+
+/// Non-function members for [`frontend_methods`].
+///
+/// # Example
+/// ```
+/// # use mirabel::{cstr, sys::semver, frontend::*};
 /// let metadata = Metadata {
 ///     frontend_name: cstr("Example\0"),
-///     version: semver {
-///         major: 0,
-///         minor: 1,
-///         patch: 0,
-///     },
-///     features,
+///     version: semver::new(0, 1, 0),
+///     features: FrontendFeatures { options: true }.into(),
 /// };
 /// ```
 pub struct Metadata {
@@ -439,8 +1889,54 @@ pub struct Metadata {
 /// to zero.
 ///
 /// # Example
-/// ```ignore
-/// create_frontend_methods::<MyFrontend>(metadata);
+/// ```
+/// # use mirabel::{error::*, event::EventAny, sdl_event::SDLEventEnum, sys::{self, semver}, *};
+/// # use mirabel::frontend::*;
+/// # use std::ptr::null_mut;
+/// /// Requires a non-empty play area.
+/// struct MyFrontend;
+///
+/// impl FrontendMethods for MyFrontend {
+///     type Options = ();
+///
+///     fn create(_: Option<&()>, display: &frontend_display_data) -> Result<Self> {
+///         if display.w == 0. {
+///             return Err(Error::new_static(ErrorCode::InvalidInput, "empty play area\0"));
+///         }
+///         Ok(Self)
+///     }
+/// #   fn runtime_opts_display(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+/// #   fn process_event(&mut self, _: Context<Self>, _: EventAny) -> Result<()> { Ok(()) }
+/// #   fn process_input(&mut self, _: Context<Self>, _: SDLEventEnum) -> Result<()> { Ok(()) }
+/// #   fn update(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+/// #   fn render(&mut self, _: Context<Self>) -> Result<()> { Ok(()) }
+/// #   fn is_game_compatible(_: GameInfo) -> CodeResult<()> { Ok(()) }
+/// }
+///
+/// let metadata = Metadata {
+///     frontend_name: cstr("Example\0"),
+///     version: semver::new(0, 1, 0),
+///     features: FrontendFeatures::default().into(),
+/// };
+/// let methods = create_frontend_methods::<MyFrontend>(metadata);
+/// let mut frontend = sys::frontend {
+///     methods: &methods,
+///     ..Default::default()
+/// };
+/// unsafe {
+///     let create = methods.create.unwrap();
+///     let mut display = frontend_display_data {
+///         w: 100.,
+///         ..Default::default()
+///     };
+///     assert_eq!(sys::ERR_ERR_OK, create(&mut frontend, &mut display, null_mut()));
+///     assert!(!frontend.data1.is_null());
+///     methods.destroy.unwrap()(&mut frontend);
+///
+///     let mut empty = frontend_display_data::default();
+///     assert_eq!(sys::ERR_ERR_INVALID_INPUT, create(&mut frontend, &mut empty, null_mut()));
+///     methods.destroy.unwrap()(&mut frontend);
+/// }
 /// ```
 pub fn create_frontend_methods<F: FrontendMethods>(metadata: Metadata) -> frontend_methods {
     frontend_methods {
@@ -484,12 +1980,23 @@ struct Aux<'l, F: FrontendMethods> {
     display_data: *mut frontend_display_data,
     /// General options for this frontend.
     ///
-    /// The options might get mutated by [`FrontendMethods::opts_display()`].
+    /// The options might get mutated by [`FrontendMethods::opts_display()`]
+    /// and [`Context::options_mut()`].
     /// Hence, we store a pointer and not a reference here.
-    options: *const F::Options,
+    options: *mut F::Options,
+    /// See [`Context::pointer_inside`].
+    pointer_inside: bool,
+    /// Started on creation and ticked before every update.
+    timer: FrameTimer,
+    /// Cleared after every successful render.
+    redraw: RedrawFlag,
     #[cfg(feature = "skia")]
     surface: Option<skia::Surface>,
-    phantom: PhantomData<(&'l mut frontend_display_data, &'l F::Options)>,
+    #[cfg(feature = "skia")]
+    surface_config: SurfaceConfig,
+    #[cfg(feature = "skia")]
+    force_raster: bool,
+    phantom: PhantomData<(&'l mut frontend_display_data, &'l mut F::Options)>,
 }
 
 impl<'l, F: FrontendMethods> Aux<'l, F>
@@ -499,7 +2006,7 @@ where
     unsafe fn init(
         frontend: *mut sys::frontend,
         display_data: *mut frontend_display_data,
-        options: *const F::Options,
+        options: *mut F::Options,
     ) {
         // Initialize data2 to zero in case creation fails.
         let data2: *mut *mut c_void = addr_of_mut!((*frontend).data2);
@@ -508,8 +2015,16 @@ where
             error: Default::default(),
             display_data,
             options,
+            // Assume the pointer is inside until told otherwise.
+            pointer_inside: true,
+            timer: FrameTimer::new(),
+            redraw: RedrawFlag::new(),
             #[cfg(feature = "skia")]
             surface: Default::default(),
+            #[cfg(feature = "skia")]
+            surface_config: Default::default(),
+            #[cfg(feature = "skia")]
+            force_raster: false,
             phantom: Default::default(),
         }));
         *data2 = aux.cast();
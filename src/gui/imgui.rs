@@ -112,6 +112,47 @@ pub fn input_scalar<D: DataType>(label: ValidCStr, value: &mut D) -> bool {
     unsafe { sys::ImGuiThin_InputScalar(label.into(), D::enum_value(), value.cast::<c_void>()) }
 }
 
+/// Create an input for a scalar `D` which stays within `[min, max]`.
+///
+/// Like [`input_scalar()`], but entered values are clamped using
+/// [`clamp_scalar()`].
+/// Use this to keep out-of-range options from reaching the game.
+///
+/// Returns `true` if `value` changed.
+pub fn input_scalar_clamped<D: DataType + PartialOrd + Copy>(
+    label: ValidCStr,
+    value: &mut D,
+    min: D,
+    max: D,
+) -> bool {
+    let old = *value;
+    input_scalar(label, value);
+    *value = clamp_scalar(*value, min, max);
+    *value != old
+}
+
+/// Clamp `value` into `[min, max]`.
+///
+/// Unlike [`Ord::clamp()`], this also works for floats and does not panic if
+/// `min > max`.
+///
+/// # Example
+/// ```
+/// # use mirabel::imgui::clamp_scalar;
+/// assert_eq!(10, clamp_scalar(42i8, -10, 10));
+/// assert_eq!(-10, clamp_scalar(-42i8, -10, 10));
+/// assert_eq!(0.5, clamp_scalar(0.5f32, 0., 1.));
+/// ```
+pub fn clamp_scalar<D: PartialOrd>(value: D, min: D, max: D) -> D {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
 /// Disable all UI elements between [`begin_disabled`] and [`end_disabled`].
 ///
 /// Only disables the elements if `disable` is `true`.
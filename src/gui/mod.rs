@@ -3,8 +3,11 @@
 
 pub mod frontend;
 pub mod imgui;
+pub mod sdl;
 pub mod sdl_event;
 
+mod shortcuts;
+
 #[cfg(feature = "skia")]
 mod skia_helper;
 
@@ -0,0 +1,85 @@
+//! Safe wrappers for some _SDL_ functions.
+
+use std::ffi::{CStr, CString};
+
+use crate::{
+    error::{Error, ErrorCode, Result},
+    sys,
+};
+
+/// Read the text on the clipboard.
+///
+/// Returns [`None`] if the clipboard is empty, holds no valid UTF-8, or
+/// cannot be read.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::ErrorCode, sdl::{clipboard_text, set_clipboard_text}};
+/// use std::{
+///     ffi::{c_char, c_int, c_void, CStr, CString},
+///     sync::Mutex,
+/// };
+///
+/// // Mock SDL, which is usually provided by mirabel.
+/// static CLIPBOARD: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+///
+/// #[no_mangle]
+/// extern "C" fn SDL_SetClipboardText(text: *const c_char) -> c_int {
+///     *CLIPBOARD.lock().unwrap() = unsafe { CStr::from_ptr(text) }.to_bytes().to_vec();
+///     0
+/// }
+/// #[no_mangle]
+/// extern "C" fn SDL_GetClipboardText() -> *mut c_char {
+///     // Like SDL, return an empty string for an empty clipboard.
+///     CString::new(CLIPBOARD.lock().unwrap().clone()).unwrap().into_raw()
+/// }
+/// #[no_mangle]
+/// extern "C" fn SDL_free(mem: *mut c_void) {
+///     drop(unsafe { CString::from_raw(mem.cast()) });
+/// }
+/// # #[no_mangle]
+/// # extern "C" fn SDL_GetError() -> *const c_char { b"\0".as_ptr().cast() }
+///
+/// assert_eq!(None, clipboard_text());
+/// set_clipboard_text("3 1 0").unwrap();
+/// assert_eq!(Some("3 1 0".to_string()), clipboard_text());
+///
+/// let error = set_clipboard_text("3\01").unwrap_err();
+/// assert_eq!(ErrorCode::InvalidInput, error.code);
+/// assert_eq!(Some("3 1 0".to_string()), clipboard_text());
+///
+/// *CLIPBOARD.lock().unwrap() = vec![b'3', 0xff];
+/// assert_eq!(None, clipboard_text());
+/// ```
+pub fn clipboard_text() -> Option<String> {
+    unsafe {
+        let text = sys::SDL_GetClipboardText();
+        if text.is_null() {
+            return None;
+        }
+        let string = CStr::from_ptr(text)
+            .to_str()
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(str::to_owned);
+        // The string is allocated by SDL and must be freed by SDL.
+        sys::SDL_free(text.cast());
+        string
+    }
+}
+
+/// Put `text` on the clipboard.
+///
+/// Fails with [`ErrorCode::InvalidInput`] if `text` contains a NUL byte.
+pub fn set_clipboard_text(text: &str) -> Result<()> {
+    let text = CString::new(text)
+        .map_err(|_| Error::new_static(ErrorCode::InvalidInput, "clipboard text contains NUL\0"))?;
+    if unsafe { sys::SDL_SetClipboardText(text.as_ptr()) } < 0 {
+        let message = unsafe { CStr::from_ptr(sys::SDL_GetError()) };
+        return Err(Error::new_dynamic(
+            ErrorCode::Nok,
+            format!("setting clipboard failed: {}", message.to_string_lossy()),
+        ));
+    }
+    Ok(())
+}
@@ -2,11 +2,12 @@
 
 use std::fmt;
 
-use crate::sys::{self, SDL_Event, SDL_WindowEvent};
+use crate::sys::{self, SDL_Event};
 
 pub use crate::sys::{
-    SDL_KeyboardEvent, SDL_MouseButtonEvent, SDL_MouseMotionEvent, SDL_MouseWheelEvent,
-    SDL_BUTTON_LEFT, SDL_BUTTON_MIDDLE, SDL_BUTTON_RIGHT, SDL_BUTTON_X1, SDL_BUTTON_X2,
+    SDL_KeyboardEvent, SDL_Keycode, SDL_Keymod, SDL_Keysym, SDL_MouseButtonEvent,
+    SDL_MouseMotionEvent, SDL_MouseWheelEvent, SDL_QuitEvent, SDL_WindowEvent, SDL_BUTTON_LEFT,
+    SDL_BUTTON_MIDDLE, SDL_BUTTON_RIGHT, SDL_BUTTON_X1, SDL_BUTTON_X2,
 };
 
 /// An _SDL_ event.
@@ -19,6 +20,8 @@ pub enum SDLEventEnum {
     MouseButtonDown(SDL_MouseButtonEvent),
     MouseButtonUp(SDL_MouseButtonEvent),
     MouseWheel(SDL_MouseWheelEvent),
+    /// The application is asked to quit.
+    Quit(SDL_QuitEvent),
     /// All other events.
     Unknown(SDL_Event),
 }
@@ -34,9 +37,31 @@ impl SDLEventEnum {
             sys::SDL_EventType_SDL_MOUSEBUTTONDOWN => Self::MouseButtonDown(event.button),
             sys::SDL_EventType_SDL_MOUSEBUTTONUP => Self::MouseButtonUp(event.button),
             sys::SDL_EventType_SDL_MOUSEWHEEL => Self::MouseWheel(event.wheel),
+            sys::SDL_EventType_SDL_QUIT => Self::Quit(event.quit),
             _ => Self::Unknown(event),
         }
     }
+
+    /// Whether this asks to close the window or quit the application.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{sdl_event::*, sys::SDL_WindowEventID_SDL_WINDOWEVENT_CLOSE};
+    /// assert!(SDLEventEnum::Quit(Default::default()).is_close_request());
+    /// let close = SDL_WindowEvent {
+    ///     event: SDL_WindowEventID_SDL_WINDOWEVENT_CLOSE as u8,
+    ///     ..Default::default()
+    /// };
+    /// assert!(SDLEventEnum::WindowEvent(close).is_close_request());
+    /// assert!(!SDLEventEnum::KeyDown(Default::default()).is_close_request());
+    /// ```
+    pub fn is_close_request(&self) -> bool {
+        match self {
+            Self::Quit(_) => true,
+            Self::WindowEvent(event) => event.kind() == WindowEventKind::Close,
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Debug for SDLEventEnum {
@@ -60,6 +85,7 @@ impl fmt::Debug for SDLEventEnum {
             Self::MouseButtonDown(e) => f.debug_tuple("MouseButtonDown").field(e).finish(),
             Self::MouseButtonUp(e) => f.debug_tuple("MouseButtonUp").field(e).finish(),
             Self::MouseWheel(e) => f.debug_tuple("MouseWheel").field(e).finish(),
+            Self::Quit(e) => f.debug_tuple("Quit").field(e).finish(),
             Self::Unknown(e) => f
                 .debug_tuple("Unknown")
                 .field(&Unknown {
@@ -70,6 +96,126 @@ impl fmt::Debug for SDLEventEnum {
     }
 }
 
+/// Decoded subtype of an [`SDL_WindowEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WindowEventKind {
+    /// The window was resized to `w`×`h` by an external event.
+    Resized {
+        w: i32,
+        h: i32,
+    },
+    /// The window size changed to `w`×`h` for any reason.
+    SizeChanged {
+        w: i32,
+        h: i32,
+    },
+    FocusGained,
+    FocusLost,
+    Exposed,
+    /// The mouse pointer entered the window.
+    Enter,
+    /// The mouse pointer left the window.
+    Leave,
+    /// The window manager requests to close the window.
+    Close,
+    /// All other window events with their raw subtype.
+    Other(u8),
+}
+
+impl WindowEventKind {
+    /// Whether the pointer is inside the window after this event.
+    ///
+    /// Returns [`None`] for events which do not change this.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::sdl_event::WindowEventKind;
+    /// let mut inside = true;
+    /// for kind in [WindowEventKind::Enter, WindowEventKind::Exposed, WindowEventKind::Leave] {
+    ///     inside = kind.pointer_inside().unwrap_or(inside);
+    ///     assert_eq!(kind != WindowEventKind::Leave, inside);
+    /// }
+    /// ```
+    #[inline]
+    pub fn pointer_inside(&self) -> Option<bool> {
+        match self {
+            Self::Enter => Some(true),
+            Self::Leave => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl SDL_WindowEvent {
+    /// Decode the subtype and its data.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{sdl_event::*, sys::SDL_WindowEventID_SDL_WINDOWEVENT_SIZE_CHANGED};
+    /// let event = SDL_WindowEvent {
+    ///     event: SDL_WindowEventID_SDL_WINDOWEVENT_SIZE_CHANGED as u8,
+    ///     data1: 640,
+    ///     data2: 480,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(WindowEventKind::SizeChanged { w: 640, h: 480 }, event.kind());
+    /// ```
+    pub fn kind(&self) -> WindowEventKind {
+        let (w, h) = (self.data1, self.data2);
+        match u32::from(self.event) {
+            sys::SDL_WindowEventID_SDL_WINDOWEVENT_RESIZED => WindowEventKind::Resized { w, h },
+            sys::SDL_WindowEventID_SDL_WINDOWEVENT_SIZE_CHANGED => {
+                WindowEventKind::SizeChanged { w, h }
+            }
+            sys::SDL_WindowEventID_SDL_WINDOWEVENT_FOCUS_GAINED => WindowEventKind::FocusGained,
+            sys::SDL_WindowEventID_SDL_WINDOWEVENT_FOCUS_LOST => WindowEventKind::FocusLost,
+            sys::SDL_WindowEventID_SDL_WINDOWEVENT_EXPOSED => WindowEventKind::Exposed,
+            sys::SDL_WindowEventID_SDL_WINDOWEVENT_ENTER => WindowEventKind::Enter,
+            sys::SDL_WindowEventID_SDL_WINDOWEVENT_LEAVE => WindowEventKind::Leave,
+            sys::SDL_WindowEventID_SDL_WINDOWEVENT_CLOSE => WindowEventKind::Close,
+            _ => WindowEventKind::Other(self.event),
+        }
+    }
+}
+
+impl SDL_MouseWheelEvent {
+    /// Scroll amount with the direction flip applied.
+    ///
+    /// Positive `y` always means scrolling up and positive `x` scrolling right.
+    /// The precise values are preferred if _SDL_ provides them.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{sdl_event::*, sys::SDL_MouseWheelDirection_SDL_MOUSEWHEEL_FLIPPED};
+    /// let normal = SDL_MouseWheelEvent {
+    ///     y: 1,
+    ///     preciseY: 1.0,
+    ///     ..Default::default()
+    /// };
+    /// let flipped = SDL_MouseWheelEvent {
+    ///     y: -1,
+    ///     preciseY: -1.0,
+    ///     direction: SDL_MouseWheelDirection_SDL_MOUSEWHEEL_FLIPPED,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!((0.0, 1.0), normal.normalized());
+    /// assert_eq!((0.0, 1.0), flipped.normalized());
+    /// ```
+    pub fn normalized(&self) -> (f32, f32) {
+        let (x, y) = if self.preciseX != 0.0 || self.preciseY != 0.0 {
+            (self.preciseX, self.preciseY)
+        } else {
+            (self.x as f32, self.y as f32)
+        };
+        if self.direction == sys::SDL_MouseWheelDirection_SDL_MOUSEWHEEL_FLIPPED {
+            (-x, -y)
+        } else {
+            (x, y)
+        }
+    }
+}
+
 /// Calculates the _SDL_ button mask from the button index.
 ///
 /// This can be used to replace the `SDL_BUTTON_*MASK` macros.
@@ -0,0 +1,109 @@
+//! Keyboard shortcut dispatch for frontends.
+
+use crate::{
+    sdl_event::SDLEventEnum,
+    sys::{
+        SDL_Keycode, SDL_Keymod, SDL_Keymod_KMOD_ALT, SDL_Keymod_KMOD_CTRL, SDL_Keymod_KMOD_GUI,
+        SDL_Keymod_KMOD_SHIFT,
+    },
+};
+
+/// Modifier groups which are compared, left and right keys are equivalent.
+///
+/// Lock modifiers like caps and num lock are ignored.
+const MODIFIER_GROUPS: [SDL_Keymod; 4] = [
+    SDL_Keymod_KMOD_CTRL,
+    SDL_Keymod_KMOD_SHIFT,
+    SDL_Keymod_KMOD_ALT,
+    SDL_Keymod_KMOD_GUI,
+];
+
+/// Expand every pressed modifier to its whole group and drop lock modifiers.
+fn normalize_modifiers(modifiers: SDL_Keymod) -> SDL_Keymod {
+    MODIFIER_GROUPS
+        .into_iter()
+        .filter(|group| modifiers & group != 0)
+        .fold(0, |acc, group| acc | group)
+}
+
+/// A registry mapping key combinations to actions of type `T`.
+///
+/// Use this to declutter [`FrontendMethods::process_input()`].
+/// Modifiers must match exactly, but left and right modifier keys are
+/// equivalent and lock modifiers (caps, num, scroll) are ignored.
+///
+/// [`FrontendMethods::process_input()`]: crate::frontend::FrontendMethods::process_input
+///
+/// # Example
+/// ```
+/// # use mirabel::{frontend::Shortcuts, sdl_event::*, sys::*};
+/// #[derive(Debug, PartialEq)]
+/// enum Action {
+///     Undo,
+/// }
+///
+/// let mut shortcuts = Shortcuts::new();
+/// shortcuts.bind(
+///     SDL_KeyCode_SDLK_z as SDL_Keycode,
+///     SDL_Keymod_KMOD_CTRL,
+///     Action::Undo,
+/// );
+///
+/// let key_down = |sym: u32, modifiers: SDL_Keymod| {
+///     SDLEventEnum::KeyDown(SDL_KeyboardEvent {
+///         keysym: SDL_Keysym {
+///             sym: sym as SDL_Keycode,
+///             mod_: modifiers as u16,
+///             ..Default::default()
+///         },
+///         ..Default::default()
+///     })
+/// };
+/// let event = key_down(SDL_KeyCode_SDLK_z, SDL_Keymod_KMOD_LCTRL | SDL_Keymod_KMOD_NUM);
+/// assert_eq!(Some(&Action::Undo), shortcuts.match_event(&event));
+/// let event = key_down(SDL_KeyCode_SDLK_z, SDL_Keymod_KMOD_RCTRL | SDL_Keymod_KMOD_SHIFT);
+/// assert_eq!(None, shortcuts.match_event(&event));
+/// assert_eq!(None, shortcuts.match_event(&key_down(SDL_KeyCode_SDLK_z, 0)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Shortcuts<T> {
+    bindings: Vec<(SDL_Keycode, SDL_Keymod, T)>,
+}
+
+impl<T> Shortcuts<T> {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Bind `key` pressed with `modifiers` to `action`.
+    ///
+    /// Use group modifiers like `KMOD_CTRL` for `modifiers`.
+    /// Earlier bindings take precedence for the same combination.
+    pub fn bind(&mut self, key: SDL_Keycode, modifiers: SDL_Keymod, action: T) -> &mut Self {
+        self.bindings
+            .push((key, normalize_modifiers(modifiers), action));
+        self
+    }
+
+    /// Returns the action bound to a key-down `event` if any.
+    pub fn match_event(&self, event: &SDLEventEnum) -> Option<&T> {
+        let SDLEventEnum::KeyDown(event) = event else {
+            return None;
+        };
+        let key = event.keysym.sym;
+        let modifiers = normalize_modifiers(event.keysym.mod_.into());
+        self.bindings
+            .iter()
+            .find(|(k, m, _)| *k == key && *m == modifiers)
+            .map(|(_, _, action)| action)
+    }
+}
+
+impl<T> Default for Shortcuts<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -3,11 +3,27 @@ use skia_safe::{
         gl::{Format, FramebufferInfo},
         BackendRenderTarget, DirectContext, SurfaceOrigin,
     },
-    ColorType, Surface,
+    paint, AlphaType, Canvas, Color4f, ColorSpace, ImageInfo, Paint, Path, Rect, Surface,
 };
 
-pub fn create_surface(width: i32, height: i32) -> Surface {
-    let mut gr_context = DirectContext::new_gl(None, None).unwrap();
+use std::ffi::CStr;
+
+use super::frontend::SurfaceConfig;
+use crate::error::{Error, ErrorCode, Result};
+
+/// Minimum _OpenGL_ version the generated bindings require.
+const MIN_GL_VERSION: (u32, u32) = (3, 0);
+
+/// Check whether an _OpenGL_ context is current on this thread.
+pub fn has_gl_context() -> bool {
+    unsafe { !gl::GetString(gl::VERSION).is_null() }
+}
+
+/// Create a surface drawing into the current _OpenGL_ framebuffer.
+///
+/// This requires a current _OpenGL_ context, see [`has_gl_context()`].
+pub fn create_surface(width: i32, height: i32, config: &SurfaceConfig) -> Surface {
+    let mut gr_context = DirectContext::new_gl(None, None).expect("Skia OpenGL context creation");
 
     let mut fboid: gl::types::GLint = 0;
     let mut samples: gl::types::GLint = 0;
@@ -25,7 +41,7 @@ pub fn create_surface(width: i32, height: i32) -> Surface {
 
     let fb_info = FramebufferInfo {
         fboid: fboid.try_into().expect("frame buffer id conversion"),
-        format: Format::RGBA8.into(),
+        format: config.format.into(),
     };
     let backend_render_target = BackendRenderTarget::new_gl(
         (width, height),
@@ -37,14 +53,177 @@ pub fn create_surface(width: i32, height: i32) -> Surface {
         &mut gr_context,
         &backend_render_target,
         SurfaceOrigin::BottomLeft,
-        ColorType::RGBA8888,
-        None,
+        config.color_type,
+        config.srgb.then(ColorSpace::new_srgb),
         None,
     )
-    .unwrap()
+    .expect("surface creation")
+}
+
+/// Create a surface drawing into main memory.
+///
+/// The pixels use the color type and color space of `config`.
+pub fn create_raster_surface(width: i32, height: i32, config: &SurfaceConfig) -> Surface {
+    let info = ImageInfo::new(
+        (width, height),
+        config.color_type,
+        AlphaType::Premul,
+        config.srgb.then(ColorSpace::new_srgb),
+    );
+    Surface::new_raster(&info, None, None).expect("raster surface creation")
+}
+
+/// Check whether the bound draw framebuffer uses sRGB encoding.
+pub fn framebuffer_is_srgb() -> bool {
+    let mut fboid: gl::types::GLint = 0;
+    let mut encoding: gl::types::GLint = 0;
+    unsafe {
+        gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid);
+        // The default framebuffer names its attachments differently.
+        let attachment = if fboid == 0 {
+            gl::BACK_LEFT
+        } else {
+            gl::COLOR_ATTACHMENT0
+        };
+        gl::GetFramebufferAttachmentParameteriv(
+            gl::DRAW_FRAMEBUFFER,
+            attachment,
+            gl::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+            &mut encoding,
+        );
+    }
+    encoding == gl::SRGB as gl::types::GLint
+}
+
+/// Query the _OpenGL_ version of the current context.
+///
+/// Returns an error if the version is older than the bindings require.
+/// Call this in [`FrontendMethods::create()`] for a clear diagnostic instead
+/// of a crash on old drivers.
+///
+/// [`FrontendMethods::create()`]: super::frontend::FrontendMethods::create
+pub fn check_gl_version() -> Result<(u32, u32)> {
+    // GL_MAJOR_VERSION is unknown before 3.0, so parse the version string.
+    let version = unsafe {
+        let string = gl::GetString(gl::VERSION);
+        if string.is_null() {
+            return Err(Error::new_static(
+                ErrorCode::FeatureUnsupported,
+                "no current OpenGL context\0",
+            ));
+        }
+        CStr::from_ptr(string.cast()).to_string_lossy()
+    };
+    let parsed = parse_gl_version(&version).ok_or_else(|| {
+        Error::new_dynamic(
+            ErrorCode::FeatureUnsupported,
+            format!("malformed OpenGL version \"{version}\""),
+        )
+    })?;
+    if parsed < MIN_GL_VERSION {
+        return Err(Error::new_dynamic(
+            ErrorCode::FeatureUnsupported,
+            format!(
+                "OpenGL {}.{} required but context provides {version}",
+                MIN_GL_VERSION.0, MIN_GL_VERSION.1
+            ),
+        ));
+    }
+    Ok(parsed)
+}
+
+/// Parse major and minor version from an _OpenGL_ version string.
+///
+/// # Example
+/// ```
+/// # use mirabel::frontend::parse_gl_version;
+/// assert_eq!(Some((3, 0)), parse_gl_version("3.0 Mesa 23.1.4"));
+/// assert_eq!(Some((4, 6)), parse_gl_version("4.6.0 NVIDIA 535.54.03"));
+/// assert_eq!(Some((3, 2)), parse_gl_version("OpenGL ES 3.2 Mesa"));
+/// assert_eq!(None, parse_gl_version("unknown"));
+/// ```
+pub fn parse_gl_version(version: &str) -> Option<(u32, u32)> {
+    let number = version
+        .split_whitespace()
+        .find(|w| w.starts_with(|c: char| c.is_ascii_digit()))?;
+    let mut parts = number.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Build the lines of a grid with `rows`×`cols` square cells as one path.
+///
+/// The grid spans from the origin to `(cols * cell, rows * cell)`.
+/// Position it via the canvas matrix and draw it with a single
+/// [`Canvas::draw_path()`](skia_safe::Canvas::draw_path) using a stroking
+/// paint.
+///
+/// # Example
+/// ```
+/// # use mirabel::frontend::{grid_path, skia};
+/// let path = grid_path(3, 3, 10.);
+/// assert_eq!(&skia::Rect::new(0., 0., 30., 30.), path.bounds());
+/// ```
+pub fn grid_path(rows: u32, cols: u32, cell: f32) -> Path {
+    let width = cols as f32 * cell;
+    let height = rows as f32 * cell;
+    let mut path = Path::new();
+    for row in 0..=rows {
+        let y = row as f32 * cell;
+        path.move_to((0., y));
+        path.line_to((width, y));
+    }
+    for col in 0..=cols {
+        let x = col as f32 * cell;
+        path.move_to((x, 0.));
+        path.line_to((x, height));
+    }
+    path
+}
+
+/// Fill `rect` with `fill` and outline it with a `border` of `width`.
+///
+/// This is the common look of a selected cell.
+/// The border is centered on the edges of `rect`.
+/// A `width` of zero (or less) skips the border instead of drawing a
+/// hairline.
+///
+/// # Example
+/// ```
+/// # use mirabel::frontend::{draw_highlight, skia::{self, Color, Color4f, Rect}};
+/// let mut surface = skia::Surface::new_raster_n32_premul((20, 20)).unwrap();
+/// let canvas = surface.canvas();
+/// canvas.clear(Color::WHITE);
+/// let rect = Rect::new(5., 5., 15., 15.);
+/// draw_highlight(canvas, rect, Color4f::from(Color::RED), Color4f::from(Color::BLUE), 2.);
+///
+/// let pixels = surface.peek_pixels().unwrap();
+/// assert_eq!(Color::RED, pixels.get_color((10, 10)));
+/// assert_eq!(Color::BLUE, pixels.get_color((5, 10)));
+/// assert_eq!(Color::WHITE, pixels.get_color((1, 1)));
+/// ```
+pub fn draw_highlight(canvas: &mut Canvas, rect: Rect, fill: Color4f, border: Color4f, width: f32) {
+    let mut paint = Paint::new(fill, None);
+    paint.set_anti_alias(true);
+    canvas.draw_rect(rect, &paint);
+    if width > 0. {
+        paint.set_color4f(border, None);
+        paint.set_style(paint::Style::Stroke);
+        paint.set_stroke_width(width);
+        canvas.draw_rect(rect, &paint);
+    }
 }
 
-mod gl {
+/// Raw _OpenGL_ 3.0 bindings as used by _mirabel_.
+///
+/// Call [`CanvasManager::begin_raw_gl()`] before and
+/// [`CanvasManager::end_raw_gl()`] after issuing raw calls, and restore the
+/// framebuffer binding and viewport before _Skia_ draws again.
+///
+/// [`CanvasManager::begin_raw_gl()`]: super::frontend::CanvasManager::begin_raw_gl
+/// [`CanvasManager::end_raw_gl()`]: super::frontend::CanvasManager::end_raw_gl
+pub mod gl {
     #![allow(clippy::unused_unit)]
     #![allow(clippy::upper_case_acronyms)]
 
@@ -0,0 +1,50 @@
+//! Checked conversions from [`move_code`]s to smaller integers.
+//!
+//! Move codes are 64 bit wide, but games often store moves in smaller types.
+//! Casting with `as` silently truncates large codes, which might come from
+//! untrusted move strings, into seemingly valid moves.
+//! These helpers return [`ErrorCode::InvalidInput`] instead.
+
+use crate::{
+    error::{Error, ErrorCode, Result},
+    game::move_code,
+};
+
+/// Convert `code` into `T` or fail if it does not fit.
+///
+/// # Example
+/// ```
+/// # use mirabel::{convert::move_code_to, error::ErrorCode};
+/// assert_eq!(Ok(7u8), move_code_to::<u8>(7).map_err(|e| e.code));
+/// assert_eq!(ErrorCode::InvalidInput, move_code_to::<u8>(256).unwrap_err().code);
+/// ```
+pub fn move_code_to<T: TryFrom<move_code>>(code: move_code) -> Result<T> {
+    T::try_from(code)
+        .map_err(|_| Error::new_static(ErrorCode::InvalidInput, "move code out of range\0"))
+}
+
+/// Convert `code` into a [`u8`] or fail if it does not fit.
+#[inline]
+pub fn move_code_to_u8(code: move_code) -> Result<u8> {
+    move_code_to(code)
+}
+
+/// Convert `code` into a [`u16`] or fail if it does not fit.
+///
+/// # Example
+/// ```
+/// # use mirabel::{convert::move_code_to_u16, error::ErrorCode};
+/// assert_eq!(u16::MAX, move_code_to_u16(u16::MAX.into()).unwrap());
+/// let error = move_code_to_u16(u64::from(u16::MAX) + 1).unwrap_err();
+/// assert_eq!(ErrorCode::InvalidInput, error.code);
+/// ```
+#[inline]
+pub fn move_code_to_u16(code: move_code) -> Result<u16> {
+    move_code_to(code)
+}
+
+/// Convert `code` into a [`u32`] or fail if it does not fit.
+#[inline]
+pub fn move_code_to_u32(code: move_code) -> Result<u32> {
+    move_code_to(code)
+}
@@ -5,34 +5,127 @@ pub use crate::sys::{
 };
 
 use crate::{
-    cstr_to_rust, cstr_to_rust_unchecked,
-    error::{ErrorString, Result},
-    from_raw_hedged,
+    cstr_to_bytes, cstr_to_rust, debug_big_move, debug_move_code,
+    error::{Error, ErrorCode, ErrorString, Result},
     game_init::GameInit,
+    move_data_view,
+    rng::GameRng,
+    slice_from_raw_hedged,
     sys::{
         self, game_feature_flags, game_methods, move_data,
         move_data_s__bindgen_ty_1 as move_data_cl, move_data_sync,
     },
-    MoveDataSync, ValidCStr, ValidCString,
+    MoveDataSync, MoveDataView, ValidCStr, ValidCString,
 };
 
 use std::{
+    cell::RefCell,
     ffi::{c_float, c_void},
+    fmt,
     ops::Deref,
     os::raw::c_char,
     ptr::{addr_of, addr_of_mut, null_mut},
     slice::from_raw_parts_mut,
+    str::from_utf8,
 };
 
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+/// Version of the _surena_ game API implemented by this crate.
+///
+/// # Example
+/// ```
+/// # use mirabel::{game::GAME_API_VERSION, sys};
+/// assert_eq!(sys::SURENA_GAME_API_VERSION, GAME_API_VERSION);
+/// ```
+pub const GAME_API_VERSION: u64 = sys::SURENA_GAME_API_VERSION;
+
+/// Check that a host uses the same game API version as this crate.
+///
+/// # Example
+/// ```
+/// # use mirabel::game::*;
+/// assert!(check_api_version(GAME_API_VERSION).is_ok());
+/// assert!(check_api_version(GAME_API_VERSION + 1).is_err());
+/// ```
+pub fn check_api_version(host: u64) -> Result<()> {
+    if host == GAME_API_VERSION {
+        Ok(())
+    } else {
+        Err(Error::new_dynamic(
+            ErrorCode::FeatureUnsupported,
+            format!("host uses game API version {host} but plugin uses {GAME_API_VERSION}"),
+        ))
+    }
+}
+
+/// Whether `player` is the [`PLAYER_RAND`] which makes chance moves.
+///
+/// With [`GameFeatures::random_moves`], [`PLAYER_RAND`] is to move at chance
+/// nodes.
+/// Its moves are drawn with [`GameMethods::get_random_move()`] and passed to
+/// [`GameMethods::make_move()`] like any other move.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::*, game::*, game_init::GameInit, helpers::timed_playouts, rng::GameRng, *};
+/// # use std::time::Duration;
+/// /// Roll a die once.
+/// #[derive(Clone, PartialEq, Eq)]
+/// struct Dice(Option<move_code>);
+///
+/// impl GameMethods for Dice {
+///     type Move = MoveCode;
+///
+///     fn to_move(&mut self) -> Result<Option<player_id>> {
+///         Ok(self.0.is_none().then_some(PLAYER_RAND))
+///     }
+///     fn get_concrete_moves(&mut self, _: player_id, _: &mut Vec<MoveCode>) -> Result<()> {
+///         panic!("chance moves must use get_random_move");
+///     }
+///     fn get_random_move(&mut self, rng: &mut GameRng) -> Result<MoveCode> {
+///         Ok((rng.below(6) + 1).into())
+///     }
+///     fn make_move(&mut self, player: player_id, mov: MoveDataSync<u64>) -> Result<()> {
+///         assert!(is_random_player(player));
+///         self.0 = Some(mov.md);
+///         Ok(())
+///     }
+/// #   fn create(_: &GameInit) -> Result<Self> { Ok(Self(None)) }
+/// #   fn player_count(&mut self) -> Result<u8> { Ok(1) }
+/// #   fn import_state(&mut self, _: Option<&str>) -> Result<()> { Ok(()) }
+/// #   fn export_state(&mut self, _: player_id, _: &mut ValidCString) -> Result<()> { Ok(()) }
+/// #   fn get_move_data(&mut self, _: player_id, _: &str) -> Result<MoveCode> { unimplemented!() }
+/// #   fn get_move_str(&mut self, _: player_id, _: MoveDataSync<u64>, _: &mut ValidCString) -> Result<()> { Ok(()) }
+/// #   fn get_results(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+/// #   fn is_legal_move(&mut self, _: player_id, _: MoveDataSync<u64>) -> Result<()> { Ok(()) }
+/// }
+///
+/// assert!(!is_random_player(1));
+/// let mut game = Dice(None);
+/// assert_eq!(1, timed_playouts(&mut game, 42, Duration::ZERO).unwrap());
+/// ```
+#[inline]
+pub fn is_random_player(player: player_id) -> bool {
+    player == PLAYER_RAND
+}
+
 /// This macro creates the `plugin_get_game_methods` function.
 ///
 /// Is must be supplied with all game structs and their [`Metadata`] structures
 /// so that they can be exported.
-/// This macro will internally call [`create_game_methods()`] to guarantee safe
-/// usage.
+/// This macro will internally call [`try_create_game_methods()`] to guarantee
+/// safe usage.
+/// Games which fail [`GameFeatures::validate()`] are logged and left out, while
+/// the remaining games are still exported.
 /// This method can only be called once but with multiple methods.
+/// The same game struct may appear multiple times, e.g., for offering
+/// different variants with their own [`Metadata`].
 /// It also exports the `plugin_init_game`, `plugin_get_game_capi_version`, and
 /// `plugin_cleanup_game` functions for you.
+/// For tests, the Rust function `registered_game_methods` returns the exported
+/// methods as a slice.
 ///
 /// # Example
 /// ```ignore
@@ -41,17 +134,73 @@ use std::{
 /// }
 /// plugin_get_game_methods!(MyGame{generate_metadata()});
 /// ```
+///
+/// At least one game must be registered:
+/// ```compile_fail
+/// # use mirabel::plugin_get_game_methods;
+/// plugin_get_game_methods!();
+/// # fn main() {}
+/// ```
+///
+/// Registering one game struct as multiple variants:
+/// ```
+/// # use mirabel::{game::*, testing::Countdown, *};
+/// # use std::ffi::CStr;
+/// fn variant(name: &str) -> Metadata {
+///     Metadata::builder()
+///         .game_name("Countdown")
+///         .variant_name(name)
+///         .impl_name("mirabel_rs")
+///         .version(0, 1, 0)
+///         .build()
+/// }
+///
+/// plugin_get_game_methods!(Countdown{variant("Standard")}, Countdown{variant("Misère")});
+///
+/// fn main() {
+///     assert!(registered_game_methods().is_empty());
+///     let mut count = 0;
+///     let mut methods = [std::ptr::null(); 2];
+///     unsafe {
+///         plugin_init_game();
+///         plugin_get_game_methods(&mut count, methods.as_mut_ptr());
+///     }
+///     assert_eq!(2, count);
+///     let names: Vec<_> = methods
+///         .iter()
+///         .map(|&m| unsafe { CStr::from_ptr((*m).variant_name) }.to_str().unwrap())
+///         .collect();
+///     assert_eq!(["Standard", "Misère"], names[..]);
+///
+///     let registered = registered_game_methods();
+///     assert_eq!(2, registered.len());
+///     let name = unsafe { CStr::from_ptr(registered[0].game_name) };
+///     assert_eq!("Countdown", name.to_str().unwrap());
+/// }
+/// ```
 #[macro_export]
 macro_rules! plugin_get_game_methods {
     ( $( $g:ty{$m:expr} ),* ) => {
+        const _: () = ::std::assert!(
+            $crate::count!($($g),*) > 0,
+            "no game methods registered",
+        );
+
         static mut PLUGIN_GAME_METHODS: ::std::mem::MaybeUninit<
             [$crate::sys::game_methods; $crate::count!($($g),*)]
         > = ::std::mem::MaybeUninit::uninit();
+        /// Number of games which passed validation.
+        static mut PLUGIN_GAME_COUNT: u32 = 0;
 
         #[no_mangle]
         unsafe extern "C" fn plugin_init_game() {
-            ::std::mem::MaybeUninit::write(&mut self::PLUGIN_GAME_METHODS,
-                [$($crate::game::create_game_methods::<$g>($m)),*]
+            let methods = ::std::mem::MaybeUninit::write(
+                &mut self::PLUGIN_GAME_METHODS,
+                ::std::array::from_fn(|_| ::std::default::Default::default()),
+            );
+            self::PLUGIN_GAME_COUNT = $crate::game::init_game_methods(
+                [$($crate::game::try_create_game_methods::<$g>($m)),*],
+                methods,
             );
         }
 
@@ -60,16 +209,26 @@ macro_rules! plugin_get_game_methods {
             count: *mut u32,
             methods: *mut *const $crate::sys::game_methods,
         ) {
-            count.write($crate::count!($($g),*));
+            count.write(self::PLUGIN_GAME_COUNT);
             if methods.is_null() {
                 return;
             }
 
-            let src = ::std::mem::MaybeUninit::assume_init_ref(
-                &self::PLUGIN_GAME_METHODS
-            );
-            for i in 0..$crate::count!($($g),*) {
-                methods.add(i).write(&src[i]);
+            for (i, src) in self::registered_game_methods().iter().enumerate() {
+                methods.add(i).write(src);
+            }
+        }
+
+        /// Return the methods exported by `plugin_get_game_methods`.
+        ///
+        /// This is empty before `plugin_init_game` was called.
+        pub fn registered_game_methods() -> &'static [$crate::sys::game_methods] {
+            unsafe {
+                // Only the first PLUGIN_GAME_COUNT entries are initialized.
+                ::std::slice::from_raw_parts(
+                    ::std::ptr::addr_of!(self::PLUGIN_GAME_METHODS).cast(),
+                    self::PLUGIN_GAME_COUNT as usize,
+                )
             }
         }
 
@@ -81,7 +240,7 @@ macro_rules! plugin_get_game_methods {
         /// This exports the game API version to the outside.
         #[no_mangle]
         pub extern "C" fn plugin_get_game_capi_version() -> u64 {
-            $crate::sys::SURENA_GAME_API_VERSION
+            $crate::game::GAME_API_VERSION
         }
     };
 }
@@ -103,7 +262,9 @@ macro_rules! surena_try {
 /// See `./mirabel/lib/surena/includes/surena/game.h` for API documentation.
 ///
 /// Games need to implement [`Drop`] for custom `destroy` handling.
-/// `clone` is handled by the [`Clone`] implementation and `compare` by [`Eq`].
+/// `clone` is handled by the [`Clone`] implementation and `compare` by
+/// [`GameMethods::game_eq()`], which defaults to [`Eq`].
+/// `copy_from` defaults to [`Clone::clone_from()`] but can be overridden.
 /// The [`Send`] bound is required by the surena API.
 ///
 /// # Example
@@ -113,24 +274,201 @@ pub trait GameMethods: Sized + Clone + Eq + Send {
     type Move: MoveData;
 
     fn create(init_info: &GameInit) -> Result<Self>;
-    fn copy_from(&mut self, other: &mut Self) -> Result<()>;
+    /// Uses [`Clone::clone_from()`] by default.
+    ///
+    /// Implement this manually if copying can reuse more resources.
+    /// An implementation always takes precedence over the default.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{game::*, game_init::GameInit, testing::Countdown};
+    /// // What `Copy` games used to write by hand.
+    /// fn copy_by_hand(game: &mut Countdown, other: &mut Countdown) {
+    ///     *game = *other;
+    /// }
+    ///
+    /// let mut other = Countdown(3);
+    /// let mut default = <Countdown as GameMethods>::create(&GameInit::Default).unwrap();
+    /// GameMethods::copy_from(&mut default, &mut other).unwrap();
+    ///
+    /// let mut manual = <Countdown as GameMethods>::create(&GameInit::Default).unwrap();
+    /// copy_by_hand(&mut manual, &mut other);
+    /// assert_eq!(manual, default);
+    /// ```
+    fn copy_from(&mut self, other: &mut Self) -> Result<()> {
+        self.clone_from(other);
+        Ok(())
+    }
+    /// Semantic equality used for `compare`, defaults to [`Eq`].
+    ///
+    /// Override this if equivalent states can differ in fields which do not
+    /// affect play, e.g., for transposition detection.
+    /// This must be an equivalence relation (reflexive, symmetric, and
+    /// transitive).
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, *};
+    /// #[derive(Clone, PartialEq, Eq)]
+    /// struct Countdown {
+    ///     counter: u64,
+    ///     initial: u64,
+    /// }
+    ///
+    /// impl SimpleGame for Countdown {
+    ///     fn game_eq(&self, other: &Self) -> bool {
+    ///         self.counter == other.counter
+    ///     }
+    /// #   fn create(_: &GameInit) -> Result<Self> { unimplemented!() }
+    /// #   fn player_count(&mut self) -> Result<u8> { Ok(1) }
+    /// #   fn import_state(&mut self, _: Option<&str>) -> Result<()> { Ok(()) }
+    /// #   fn export_state(&mut self, _: player_id, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn players_to_move(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn get_concrete_moves(&mut self, _: player_id, _: &mut Vec<MoveCode>) -> Result<()> { Ok(()) }
+    /// #   fn get_move_data(&mut self, _: player_id, _: &str) -> Result<move_code> { Ok(0) }
+    /// #   fn get_move_str(&mut self, _: player_id, _: move_code, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn make_move(&mut self, _: player_id, _: move_code) -> Result<()> { Ok(()) }
+    /// #   fn get_results(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn is_legal_move(&mut self, _: player_id, _: move_code) -> Result<()> { Ok(()) }
+    /// }
+    ///
+    /// let a = Countdown { counter: 3, initial: 5 };
+    /// let b = Countdown { counter: 3, initial: 7 };
+    /// assert!(a != b);
+    /// assert!(GameMethods::game_eq(&a, &b));
+    /// ```
+    fn game_eq(&self, other: &Self) -> bool {
+        self == other
+    }
     fn player_count(&mut self) -> Result<u8>;
+    /// Maximum player count of this game, e.g., for sizing per-player UI.
+    ///
+    /// _surena_'s [`game_methods`] have no field for this.
+    /// Hence, only Rust code which knows the game type, e.g., a frontend
+    /// bundled with the game, can query it.
+    /// Defaults to [`Self::player_count()`] of a game created with
+    /// [`GameInit::Default`], so the default does create a game.
+    /// Override this if the player count depends on the options.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{game::*, testing::Countdown};
+    /// assert_eq!(1, <Countdown as GameMethods>::max_players().unwrap());
+    /// ```
+    fn max_players() -> Result<u8> {
+        Self::create(&GameInit::Default)?.player_count()
+    }
     fn import_state(&mut self, string: Option<&str>) -> Result<()>;
     fn export_state(&mut self, player: player_id, str_buf: &mut ValidCString) -> Result<()>;
-    fn players_to_move(&mut self, players: &mut Vec<player_id>) -> Result<()>;
+    /// `players` is empty but keeps its capacity between calls.
+    ///
+    /// Implement exactly one of this and [`Self::to_move()`].
+    /// Defaults to pushing the player from [`Self::to_move()`] if any.
+    /// Games with simultaneous moves need to override this.
+    fn players_to_move(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+        players.extend(self.to_move()?);
+        Ok(())
+    }
+    /// Implement exactly one of this and [`Self::players_to_move()`].
+    ///
+    /// Return the single player to move or [`None`] if the game is over.
+    /// Fails with [`ErrorCode::FeatureUnsupported`] by default, so that games
+    /// implementing neither report an error instead of aborting the host.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, testing::Countdown, *};
+    /// let mut players = vec![];
+    /// GameMethods::players_to_move(&mut Countdown(1), &mut players).unwrap();
+    /// assert_eq!(vec![1], players);
+    /// players.clear();
+    /// GameMethods::players_to_move(&mut Countdown(0), &mut players).unwrap();
+    /// assert!(players.is_empty());
+    /// ```
+    fn to_move(&mut self) -> Result<Option<player_id>> {
+        Err(to_move_unimplemented())
+    }
+    /// Check whether `player` may query moves, e.g., in
+    /// [`Self::get_concrete_moves()`].
+    ///
+    /// Returns `true` if `player` is to move and `false` if nobody is to move
+    /// because the game is over.
+    /// Fails with [`ErrorCode::InvalidInput`] if other players are to move.
+    /// Uses [`Self::players_to_move()`] with a reused buffer by default.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, testing::Countdown, *};
+    /// assert!(GameMethods::check_to_move(&mut Countdown(1), 1).unwrap());
+    /// assert!(!GameMethods::check_to_move(&mut Countdown(0), 1).unwrap());
+    /// let error = GameMethods::check_to_move(&mut Countdown(1), 2).unwrap_err();
+    /// assert_eq!(ErrorCode::InvalidInput, error.code);
+    /// ```
+    fn check_to_move(&mut self, player: player_id) -> Result<bool> {
+        check_players_to_move(player, |players| self.players_to_move(players))
+    }
+    /// Leave `moves` empty and succeed if no legal moves exist, e.g., because
+    /// the game is over.
+    /// Querying a player who is not to move should fail with
+    /// [`ErrorCode::InvalidInput`] instead, see [`Self::check_to_move()`].
     fn get_concrete_moves(&mut self, player: player_id, moves: &mut Vec<Self::Move>) -> Result<()>;
     fn get_move_data(&mut self, player: player_id, string: &str) -> Result<Self::Move>;
+    /// Parse a move from raw `bytes`, which need not be valid UTF-8.
+    ///
+    /// _surena_ calls this instead of [`Self::get_move_data()`].
+    /// Big-move games can override it to parse binary move encodings.
+    /// The `bytes` never contain NUL because they come from a C string.
+    /// Defaults to decoding UTF-8 and calling [`Self::get_move_data()`],
+    /// which fails with [`ErrorCode::InvalidInput`] for invalid UTF-8.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, *};
+    /// #[derive(Clone, PartialEq, Eq)]
+    /// struct Binary;
+    ///
+    /// impl GameMethods for Binary {
+    ///     type Move = MixedMove;
+    ///
+    ///     fn get_move_data_bytes(&mut self, _: player_id, bytes: &[u8]) -> Result<MixedMove> {
+    ///         MixedMove::try_from_bytes(bytes)
+    ///     }
+    /// #   fn create(_: &GameInit) -> Result<Self> { Ok(Binary) }
+    /// #   fn player_count(&mut self) -> Result<u8> { Ok(1) }
+    /// #   fn import_state(&mut self, _: Option<&str>) -> Result<()> { Ok(()) }
+    /// #   fn export_state(&mut self, _: player_id, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn players_to_move(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn get_concrete_moves(&mut self, _: player_id, _: &mut Vec<MixedMove>) -> Result<()> { Ok(()) }
+    /// #   fn get_move_data(&mut self, _: player_id, _: &str) -> Result<MixedMove> { unimplemented!() }
+    /// #   fn get_move_str(&mut self, _: player_id, _: MoveDataSync<MixedMoveRust>, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn make_move(&mut self, _: player_id, _: MoveDataSync<MixedMoveRust>) -> Result<()> { Ok(()) }
+    /// #   fn get_results(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn is_legal_move(&mut self, _: player_id, _: MoveDataSync<MixedMoveRust>) -> Result<()> { Ok(()) }
+    /// }
+    ///
+    /// let mov = Binary.get_move_data_bytes(1, &[0xff, 0xfe, 0x01]).unwrap();
+    /// assert_eq!(Some(&[0xff, 0xfe, 0x01][..]), mov.as_bytes());
+    /// ```
+    fn get_move_data_bytes(&mut self, player: player_id, bytes: &[u8]) -> Result<Self::Move> {
+        let string = from_utf8(bytes).map_err(|_| {
+            Error::new_static(ErrorCode::InvalidInput, "move string is not valid UTF-8\0")
+        })?;
+        self.get_move_data(player, string)
+    }
     fn get_move_str(
         &mut self,
         player: player_id,
         mov: MoveDataSync<<Self::Move as MoveData>::Rust<'_>>,
         str_buf: &mut ValidCString,
     ) -> Result<()>;
+    /// `player` is [`PLAYER_RAND`] for chance moves, see
+    /// [`is_random_player()`].
     fn make_move(
         &mut self,
         player: player_id,
         mov: MoveDataSync<<Self::Move as MoveData>::Rust<'_>>,
     ) -> Result<()>;
+    /// `players` is empty but keeps its capacity between calls.
     fn get_results(&mut self, players: &mut Vec<player_id>) -> Result<()>;
     #[allow(clippy::wrong_self_convention)]
     fn is_legal_move(
@@ -138,6 +476,89 @@ pub trait GameMethods: Sized + Clone + Eq + Send {
         player: player_id,
         mov: MoveDataSync<<Self::Move as MoveData>::Rust<'_>>,
     ) -> Result<()>;
+    /// Receive the [`sync_ctr`](sys::game::sync_ctr) of this game before each
+    /// [`Self::is_legal_move()`] and [`Self::make_move()`].
+    ///
+    /// Games with hidden information can keep it to validate the sync
+    /// counters of incoming moves.
+    /// The counter belongs to the host, which also advances it.
+    /// Does nothing by default.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, *};
+    /// # use std::{ffi::CStr, fmt::Write, ptr::null};
+    /// #[derive(Clone, PartialEq, Eq)]
+    /// struct Secret {
+    ///     sync_ctr: u64,
+    /// }
+    ///
+    /// impl GameMethods for Secret {
+    ///     type Move = MoveCode;
+    ///
+    ///     fn set_sync_ctr(&mut self, sync_ctr: u64) {
+    ///         self.sync_ctr = sync_ctr;
+    ///     }
+    ///     fn make_move(&mut self, _: player_id, mov: MoveDataSync<u64>) -> Result<()> {
+    ///         if mov.sync_ctr != self.sync_ctr {
+    ///             return Err(Error::new_static(ErrorCode::InvalidMove, "stale move\0"));
+    ///         }
+    ///         Ok(())
+    ///     }
+    ///     fn export_state(&mut self, _: player_id, str_buf: &mut ValidCString) -> Result<()> {
+    ///         write!(str_buf, "{}", self.sync_ctr)?;
+    ///         Ok(())
+    ///     }
+    /// #   fn create(_: &GameInit) -> Result<Self> { Ok(Self { sync_ctr: 0 }) }
+    /// #   fn player_count(&mut self) -> Result<u8> { Ok(1) }
+    /// #   fn import_state(&mut self, _: Option<&str>) -> Result<()> { Ok(()) }
+    /// #   fn players_to_move(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn get_concrete_moves(&mut self, _: player_id, _: &mut Vec<MoveCode>) -> Result<()> { Ok(()) }
+    /// #   fn get_move_data(&mut self, _: player_id, _: &str) -> Result<MoveCode> { Ok(0.into()) }
+    /// #   fn get_move_str(&mut self, _: player_id, _: MoveDataSync<u64>, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn get_results(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn is_legal_move(&mut self, _: player_id, _: MoveDataSync<u64>) -> Result<()> { Ok(()) }
+    /// }
+    ///
+    /// let methods = create_game_methods::<Secret>(
+    ///     Metadata::builder()
+    ///         .game_name("Secret")
+    ///         .variant_name("Standard")
+    ///         .impl_name("mirabel_rs")
+    ///         .build(),
+    /// );
+    /// let mut game = sys::game {
+    ///     methods: &methods,
+    ///     sync_ctr: 5,
+    ///     ..Default::default()
+    /// };
+    /// unsafe {
+    ///     let mut init = sys::game_init {
+    ///         source_type: sys::GAME_INIT_SOURCE_TYPE_E_GAME_INIT_SOURCE_TYPE_DEFAULT,
+    ///         ..Default::default()
+    ///     };
+    ///     assert_eq!(sys::ERR_ERR_OK, methods.create.unwrap()(&mut game, &mut init));
+    ///     let mov = sys::move_data_sync {
+    ///         md: *MoveCode::from(1),
+    ///         sync_ctr: 5,
+    ///     };
+    ///     assert_eq!(sys::ERR_ERR_OK, methods.make_move.unwrap()(&mut game, 1, mov));
+    ///     // The wrapper leaves the counter of the host alone.
+    ///     assert_eq!(5, game.sync_ctr);
+    ///
+    ///     // The game saw the counter of the move.
+    ///     let (mut size, mut state) = (0, null());
+    ///     methods.export_state.unwrap()(&mut game, 1, &mut size, &mut state);
+    ///     assert_eq!("5", CStr::from_ptr(state).to_str().unwrap());
+    ///
+    ///     // After the host advanced the counter, the old one is stale.
+    ///     game.sync_ctr = 6;
+    ///     assert_ne!(sys::ERR_ERR_OK, methods.make_move.unwrap()(&mut game, 1, mov));
+    ///     methods.destroy.unwrap()(&mut game);
+    /// }
+    /// ```
+    #[allow(unused_variables)]
+    fn set_sync_ctr(&mut self, sync_ctr: u64) {}
 
     /// Must be implemented when [`GameFeatures::options`] is enabled.
     #[allow(unused_variables)]
@@ -168,8 +589,12 @@ pub trait GameMethods: Sized + Clone + Eq + Send {
         unimplemented!("move_to_action")
     }
     /// Must be implemented when [`GameFeatures::random_moves`] is enabled.
+    ///
+    /// Draws the move of [`PLAYER_RAND`] at a chance node.
+    /// `rng` is freshly seeded with the seed supplied by surena.
+    /// Only wired up if [`GameFeatures::random_moves`] is enabled.
     #[allow(unused_variables)]
-    fn get_random_move(&mut self, seed: u64) -> Result<Self::Move> {
+    fn get_random_move(&mut self, rng: &mut GameRng) -> Result<Self::Move> {
         unimplemented!("get_random_move")
     }
     /// Must be implemented when [`GameFeatures::random_moves`] or
@@ -178,11 +603,462 @@ pub trait GameMethods: Sized + Clone + Eq + Send {
     fn redact_keep_state(&mut self, players: &[player_id]) -> Result<()> {
         unimplemented!("redact_keep_state")
     }
+    /// Must be implemented when [`GameFeatures::pooled_moves`] is enabled.
+    ///
+    /// This replaces [`Self::get_concrete_moves()`] and avoids allocating
+    /// every big move separately.
+    /// See [`MoveBuilder`] for details.
+    #[allow(unused_variables)]
+    fn get_concrete_moves_pooled(
+        &mut self,
+        player: player_id,
+        moves: &mut MoveBuilder,
+    ) -> Result<()> {
+        unimplemented!("get_concrete_moves_pooled")
+    }
+    /// Must be implemented when [`GameFeatures::print`] is enabled.
+    #[allow(unused_variables)]
+    fn print(&mut self, player: player_id, str_buf: &mut ValidCString) -> Result<()> {
+        unimplemented!("print")
+    }
+    /// Must be implemented when [`GameFeatures::serializable`] is enabled.
+    ///
+    /// Append the binary state of the game to the empty `buf`.
+    /// [`Self::create()`] must restore an equal game from
+    /// [`GameInit::Serialized`].
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, *};
+    /// #[derive(Clone, Debug, PartialEq, Eq)]
+    /// struct Nim {
+    ///     counter: u8,
+    ///     turn: bool,
+    /// }
+    ///
+    /// impl SimpleGame for Nim {
+    ///     fn create(init_info: &GameInit) -> Result<Self> {
+    ///         match init_info.bytes() {
+    ///             None => Ok(Self { counter: 7, turn: false }),
+    ///             Some(&[counter, turn @ (0 | 1)]) => Ok(Self { counter, turn: turn == 1 }),
+    ///             Some(_) => Err(Error::new_static(ErrorCode::InvalidInput, "malformed state\0")),
+    ///         }
+    ///     }
+    ///     fn serialize(&mut self, _: player_id, buf: &mut Vec<u8>) -> Result<()> {
+    ///         buf.extend([self.counter, self.turn.into()]);
+    ///         Ok(())
+    ///     }
+    ///     fn make_move(&mut self, _: player_id, mov: move_code) -> Result<()> {
+    ///         self.counter -= mov as u8;
+    ///         self.turn = !self.turn;
+    ///         Ok(())
+    ///     }
+    /// #   fn player_count(&mut self) -> Result<u8> { Ok(2) }
+    /// #   fn import_state(&mut self, _: Option<&str>) -> Result<()> { Ok(()) }
+    /// #   fn export_state(&mut self, _: player_id, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn players_to_move(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn get_concrete_moves(&mut self, _: player_id, _: &mut Vec<MoveCode>) -> Result<()> { Ok(()) }
+    /// #   fn get_move_data(&mut self, _: player_id, _: &str) -> Result<move_code> { Ok(0) }
+    /// #   fn get_move_str(&mut self, _: player_id, _: move_code, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn get_results(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn is_legal_move(&mut self, _: player_id, _: move_code) -> Result<()> { Ok(()) }
+    /// }
+    ///
+    /// let methods = create_game_methods::<Nim>(
+    ///     Metadata::builder()
+    ///         .game_name("Nim")
+    ///         .variant_name("Standard")
+    ///         .impl_name("mirabel_rs")
+    ///         .features(GameFeatures {
+    ///             serializable: true,
+    ///             ..Default::default()
+    ///         })
+    ///         .build(),
+    /// );
+    /// let mut game = sys::game {
+    ///     methods: &methods,
+    ///     ..Default::default()
+    /// };
+    /// let mut init = sys::game_init {
+    ///     source_type: sys::GAME_INIT_SOURCE_TYPE_E_GAME_INIT_SOURCE_TYPE_DEFAULT,
+    ///     ..Default::default()
+    /// };
+    /// let mut blob = std::ptr::null();
+    /// let restored = unsafe {
+    ///     methods.create.unwrap()(&mut game, &mut init);
+    ///     let mov = sys::move_data_sync {
+    ///         md: *MoveCode::from(2),
+    ///         sync_ctr: game.sync_ctr,
+    ///     };
+    ///     methods.make_move.unwrap()(&mut game, 1, mov);
+    ///     assert_eq!(sys::ERR_ERR_OK, methods.serialize.unwrap()(&mut game, PLAYER_NONE, &mut blob));
+    ///
+    ///     let bytes = std::slice::from_raw_parts((*blob).data.cast::<u8>(), (*blob).len);
+    ///     let restored = <Nim as GameMethods>::create(&GameInit::serialized(bytes));
+    ///     methods.destroy.unwrap()(&mut game);
+    ///     restored.unwrap()
+    /// };
+    /// assert_eq!(Nim { counter: 5, turn: true }, restored);
+    ///
+    /// assert!(<Nim as GameMethods>::create(&GameInit::serialized(&[5])).is_err());
+    /// ```
+    #[allow(unused_variables)]
+    fn serialize(&mut self, player: player_id, buf: &mut Vec<u8>) -> Result<()> {
+        unimplemented!("serialize")
+    }
+    /// Must be implemented when [`GameFeatures::id`] is enabled.
+    ///
+    /// Return an identifier of the current state, e.g., a hash.
+    fn id(&mut self) -> Result<u64> {
+        unimplemented!("id")
+    }
+    /// Check whether the game is over.
+    ///
+    /// This is not part of the _surena_ API but a helper for, e.g., self-play
+    /// loops.
+    /// The default checks whether [`Self::players_to_move()`] returns no
+    /// players, which allocates.
+    /// Override this if there is a cheaper way.
+    /// See [`testing::is_terminal()`](crate::testing::is_terminal) for the
+    /// same check through the exported methods.
+    fn is_terminal(&mut self) -> Result<bool> {
+        let mut players = vec![];
+        self.players_to_move(&mut players)?;
+        Ok(players.is_empty())
+    }
+    /// Dump the internal state for debugging.
+    ///
+    /// _surena_ has no method for this, so [`Self::print()`] stays unchanged
+    /// for all players.
+    /// Instead, [`snapshot()`](crate::testing::snapshot) includes the dump if
+    /// [`GameFeatures::debug_print`] is enabled.
+    /// The default forwards to [`Self::print()`] for [`PLAYER_NONE`].
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, testing::snapshot, *};
+    /// use std::fmt::Write;
+    ///
+    /// #[derive(Clone, PartialEq, Eq)]
+    /// struct Nim {
+    ///     counter: u64,
+    ///     max_sub: u64,
+    ///     turn: bool,
+    /// }
+    ///
+    /// impl SimpleGame for Nim {
+    ///     fn print(&mut self, _: player_id, str_buf: &mut ValidCString) -> Result<()> {
+    ///         writeln!(str_buf, "{} left", self.counter)?;
+    ///         Ok(())
+    ///     }
+    ///     fn debug_print(&mut self, str_buf: &mut ValidCString) -> Result<()> {
+    ///         let Self { counter, max_sub, turn } = self;
+    ///         writeln!(str_buf, "counter: {counter}, max_sub: {max_sub}, turn: {turn}")?;
+    ///         Ok(())
+    ///     }
+    /// #   fn create(_: &GameInit) -> Result<Self> { Ok(Self { counter: 7, max_sub: 3, turn: false }) }
+    /// #   fn player_count(&mut self) -> Result<u8> { Ok(2) }
+    /// #   fn import_state(&mut self, _: Option<&str>) -> Result<()> { Ok(()) }
+    /// #   fn export_state(&mut self, _: player_id, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn players_to_move(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn get_concrete_moves(&mut self, _: player_id, _: &mut Vec<MoveCode>) -> Result<()> { Ok(()) }
+    /// #   fn get_move_data(&mut self, _: player_id, _: &str) -> Result<move_code> { Ok(0) }
+    /// #   fn get_move_str(&mut self, _: player_id, _: move_code, _: &mut ValidCString) -> Result<()> { Ok(()) }
+    /// #   fn make_move(&mut self, _: player_id, _: move_code) -> Result<()> { Ok(()) }
+    /// #   fn get_results(&mut self, _: &mut Vec<player_id>) -> Result<()> { Ok(()) }
+    /// #   fn is_legal_move(&mut self, _: player_id, _: move_code) -> Result<()> { Ok(()) }
+    /// }
+    ///
+    /// let features = GameFeatures {
+    ///     print: true,
+    ///     debug_print: true,
+    ///     ..Default::default()
+    /// };
+    /// let mut game = <Nim as GameMethods>::create(&GameInit::Default).unwrap();
+    /// let snapshot = snapshot(&mut game, PLAYER_NONE, &features).unwrap();
+    /// assert_eq!(Some("7 left\n"), snapshot.print.as_deref());
+    /// let debug = snapshot.debug.unwrap();
+    /// for field in ["counter: 7", "max_sub: 3", "turn: false"] {
+    ///     assert!(debug.contains(field), "{field} missing in {debug:?}");
+    /// }
+    /// ```
+    fn debug_print(&mut self, str_buf: &mut ValidCString) -> Result<()> {
+        self.print(PLAYER_NONE, str_buf)
+    }
+}
+
+/// Simplified variant of [`GameMethods`] for perfect-information games.
+///
+/// Moves are plain [`move_code`]s and sync counters are hidden.
+/// Every [`SimpleGame`] automatically implements [`GameMethods`] using
+/// [`MoveCode`]s and [`SYNC_CTR_DEFAULT`].
+/// Hence, it can be passed to [`plugin_get_game_methods!()`] directly.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::*, game::*, game_init::GameInit, *};
+/// use std::fmt::Write;
+///
+/// #[derive(Clone, PartialEq, Eq, Debug)]
+/// struct Countdown(u64);
+///
+/// impl SimpleGame for Countdown {
+///     fn create(_init_info: &GameInit) -> Result<Self> {
+///         Ok(Self(3))
+///     }
+///     fn player_count(&mut self) -> Result<u8> {
+///         Ok(1)
+///     }
+///     fn import_state(&mut self, _string: Option<&str>) -> Result<()> {
+///         Ok(())
+///     }
+///     fn export_state(&mut self, _player: player_id, str_buf: &mut ValidCString) -> Result<()> {
+///         write!(str_buf, "{}", self.0)?;
+///         Ok(())
+///     }
+///     fn players_to_move(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+///         if self.0 > 0 {
+///             players.push(1);
+///         }
+///         Ok(())
+///     }
+///     fn get_concrete_moves(&mut self, _player: player_id, moves: &mut Vec<MoveCode>) -> Result<()> {
+///         moves.extend_codes(1..=self.0);
+///         Ok(())
+///     }
+///     fn get_move_data(&mut self, _player: player_id, string: &str) -> Result<move_code> {
+///         Ok(string.parse()?)
+///     }
+///     fn get_move_str(&mut self, _player: player_id, mov: move_code, str_buf: &mut ValidCString) -> Result<()> {
+///         write!(str_buf, "{mov}")?;
+///         Ok(())
+///     }
+///     fn make_move(&mut self, _player: player_id, mov: move_code) -> Result<()> {
+///         self.0 -= mov;
+///         Ok(())
+///     }
+///     fn get_results(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+///         players.push(1);
+///         Ok(())
+///     }
+///     fn is_legal_move(&mut self, _player: player_id, mov: move_code) -> Result<()> {
+///         if (1..=self.0).contains(&mov) {
+///             Ok(())
+///         } else {
+///             Err(ErrorCode::InvalidMove.into())
+///         }
+///     }
+/// }
+///
+/// let mut game = <Countdown as GameMethods>::create(&GameInit::Default).unwrap();
+/// let mov = GameMethods::get_move_data(&mut game, 1, "2").unwrap();
+/// GameMethods::make_move(&mut game, 1, MoveDataSync::with_default(mov.to_rust())).unwrap();
+/// assert_eq!(Countdown(1), game);
+/// assert!(GameMethods::is_legal_move(&mut game, 1, MoveDataSync::with_default(2)).is_err());
+/// ```
+pub trait SimpleGame: Sized + Clone + Eq + Send {
+    fn create(init_info: &GameInit) -> Result<Self>;
+    /// Uses [`Clone::clone_from()`] by default.
+    fn copy_from(&mut self, other: &mut Self) -> Result<()> {
+        self.clone_from(other);
+        Ok(())
+    }
+    /// See [`GameMethods::game_eq()`].
+    fn game_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+    fn player_count(&mut self) -> Result<u8>;
+    /// See [`GameMethods::max_players()`].
+    fn max_players() -> Result<u8> {
+        SimpleGame::player_count(&mut SimpleGame::create(&GameInit::Default)?)
+    }
+    fn import_state(&mut self, string: Option<&str>) -> Result<()>;
+    fn export_state(&mut self, player: player_id, str_buf: &mut ValidCString) -> Result<()>;
+    /// Implement exactly one of this and [`Self::to_move()`], see
+    /// [`GameMethods::players_to_move()`].
+    fn players_to_move(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+        players.extend(self.to_move()?);
+        Ok(())
+    }
+    /// Implement exactly one of this and [`Self::players_to_move()`], see
+    /// [`GameMethods::to_move()`].
+    fn to_move(&mut self) -> Result<Option<player_id>> {
+        Err(to_move_unimplemented())
+    }
+    /// See [`GameMethods::check_to_move()`].
+    fn check_to_move(&mut self, player: player_id) -> Result<bool> {
+        check_players_to_move(player, |players| SimpleGame::players_to_move(self, players))
+    }
+    fn get_concrete_moves(&mut self, player: player_id, moves: &mut Vec<MoveCode>) -> Result<()>;
+    fn get_move_data(&mut self, player: player_id, string: &str) -> Result<move_code>;
+    fn get_move_str(
+        &mut self,
+        player: player_id,
+        mov: move_code,
+        str_buf: &mut ValidCString,
+    ) -> Result<()>;
+    fn make_move(&mut self, player: player_id, mov: move_code) -> Result<()>;
+    /// `players` is empty but keeps its capacity between calls.
+    fn get_results(&mut self, players: &mut Vec<player_id>) -> Result<()>;
+    #[allow(clippy::wrong_self_convention)]
+    fn is_legal_move(&mut self, player: player_id, mov: move_code) -> Result<()>;
+
+    /// Must be implemented when [`GameFeatures::options`] is enabled.
+    #[allow(unused_variables)]
+    fn export_options(&mut self, player: player_id, str_buf: &mut ValidCString) -> Result<()> {
+        unimplemented!("export_options")
+    }
     /// Must be implemented when [`GameFeatures::print`] is enabled.
     #[allow(unused_variables)]
     fn print(&mut self, player: player_id, str_buf: &mut ValidCString) -> Result<()> {
         unimplemented!("print")
     }
+    /// Must be implemented when [`GameFeatures::serializable`] is enabled.
+    #[allow(unused_variables)]
+    fn serialize(&mut self, player: player_id, buf: &mut Vec<u8>) -> Result<()> {
+        unimplemented!("serialize")
+    }
+    /// See [`GameMethods::debug_print()`].
+    fn debug_print(&mut self, str_buf: &mut ValidCString) -> Result<()> {
+        self.print(PLAYER_NONE, str_buf)
+    }
+    /// See [`GameMethods::id()`].
+    fn id(&mut self) -> Result<u64> {
+        unimplemented!("id")
+    }
+    /// See [`GameMethods::is_terminal()`].
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{game::*, testing::Countdown};
+    /// // Countdown only implements to_move().
+    /// assert!(GameMethods::is_terminal(&mut Countdown(0)).unwrap());
+    /// assert!(!GameMethods::is_terminal(&mut Countdown(3)).unwrap());
+    /// ```
+    fn is_terminal(&mut self) -> Result<bool> {
+        let mut players = vec![];
+        self.players_to_move(&mut players)?;
+        Ok(players.is_empty())
+    }
+}
+
+impl<T: SimpleGame> GameMethods for T {
+    type Move = MoveCode;
+
+    #[inline]
+    fn create(init_info: &GameInit) -> Result<Self> {
+        SimpleGame::create(init_info)
+    }
+
+    #[inline]
+    fn copy_from(&mut self, other: &mut Self) -> Result<()> {
+        SimpleGame::copy_from(self, other)
+    }
+
+    #[inline]
+    fn game_eq(&self, other: &Self) -> bool {
+        SimpleGame::game_eq(self, other)
+    }
+
+    #[inline]
+    fn player_count(&mut self) -> Result<u8> {
+        SimpleGame::player_count(self)
+    }
+
+    #[inline]
+    fn max_players() -> Result<u8> {
+        <Self as SimpleGame>::max_players()
+    }
+
+    #[inline]
+    fn import_state(&mut self, string: Option<&str>) -> Result<()> {
+        SimpleGame::import_state(self, string)
+    }
+
+    #[inline]
+    fn export_state(&mut self, player: player_id, str_buf: &mut ValidCString) -> Result<()> {
+        SimpleGame::export_state(self, player, str_buf)
+    }
+
+    #[inline]
+    fn players_to_move(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+        SimpleGame::players_to_move(self, players)
+    }
+
+    #[inline]
+    fn to_move(&mut self) -> Result<Option<player_id>> {
+        SimpleGame::to_move(self)
+    }
+
+    #[inline]
+    fn check_to_move(&mut self, player: player_id) -> Result<bool> {
+        SimpleGame::check_to_move(self, player)
+    }
+
+    #[inline]
+    fn get_concrete_moves(&mut self, player: player_id, moves: &mut Vec<MoveCode>) -> Result<()> {
+        SimpleGame::get_concrete_moves(self, player, moves)
+    }
+
+    #[inline]
+    fn get_move_data(&mut self, player: player_id, string: &str) -> Result<MoveCode> {
+        SimpleGame::get_move_data(self, player, string).map(Into::into)
+    }
+
+    #[inline]
+    fn get_move_str(
+        &mut self,
+        player: player_id,
+        mov: MoveDataSync<move_code>,
+        str_buf: &mut ValidCString,
+    ) -> Result<()> {
+        SimpleGame::get_move_str(self, player, mov.md, str_buf)
+    }
+
+    #[inline]
+    fn make_move(&mut self, player: player_id, mov: MoveDataSync<move_code>) -> Result<()> {
+        SimpleGame::make_move(self, player, mov.md)
+    }
+
+    #[inline]
+    fn get_results(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+        SimpleGame::get_results(self, players)
+    }
+
+    #[inline]
+    fn is_legal_move(&mut self, player: player_id, mov: MoveDataSync<move_code>) -> Result<()> {
+        SimpleGame::is_legal_move(self, player, mov.md)
+    }
+
+    #[inline]
+    fn export_options(&mut self, player: player_id, str_buf: &mut ValidCString) -> Result<()> {
+        SimpleGame::export_options(self, player, str_buf)
+    }
+
+    #[inline]
+    fn print(&mut self, player: player_id, str_buf: &mut ValidCString) -> Result<()> {
+        SimpleGame::print(self, player, str_buf)
+    }
+
+    #[inline]
+    fn serialize(&mut self, player: player_id, buf: &mut Vec<u8>) -> Result<()> {
+        SimpleGame::serialize(self, player, buf)
+    }
+
+    #[inline]
+    fn debug_print(&mut self, str_buf: &mut ValidCString) -> Result<()> {
+        SimpleGame::debug_print(self, str_buf)
+    }
+
+    #[inline]
+    fn id(&mut self) -> Result<u64> {
+        SimpleGame::id(self)
+    }
+
+    #[inline]
+    fn is_terminal(&mut self) -> Result<bool> {
+        SimpleGame::is_terminal(self)
+    }
 }
 
 unsafe extern "C" fn get_last_error_wrapped<G: GameMethods>(game: *mut sys::game) -> *const c_char {
@@ -213,7 +1089,7 @@ unsafe extern "C" fn export_options_wrapped<G: GameMethods>(
 ) -> sys::error_code {
     let (aux, game) = get_both::<G>(game);
     let str_buf = &mut aux.str_buf;
-    *str_buf = Default::default();
+    str_buf.clear();
     surena_try!(aux, game.export_options(player, str_buf));
 
     ret_str.write(str_buf.as_ptr());
@@ -257,6 +1133,7 @@ unsafe extern "C" fn copy_from_wrapped<G: GameMethods>(
 ) -> sys::error_code {
     let other = get_data::<G>(other);
     let (aux, game) = get_both::<G>(game);
+    aux.move_cache = None;
     surena_try!(aux, game.copy_from(other));
 
     sys::ERR_ERR_OK
@@ -268,7 +1145,7 @@ unsafe extern "C" fn compare_wrapped<G: GameMethods>(
     ret_equal: *mut bool,
 ) -> sys::error_code {
     let other = get_data::<G>(other);
-    ret_equal.write(get_data::<G>(game).eq(&other));
+    ret_equal.write(get_data::<G>(game).game_eq(&other));
 
     sys::ERR_ERR_OK
 }
@@ -290,6 +1167,7 @@ unsafe extern "C" fn import_state_wrapped<G: GameMethods>(
 ) -> sys::error_code {
     let string = cstr_to_rust(string);
     let (aux, game) = get_both::<G>(game);
+    aux.move_cache = None;
     surena_try!(aux, game.import_state(string));
 
     sys::ERR_ERR_OK
@@ -302,7 +1180,7 @@ unsafe extern "C" fn export_state_wrapped<G: GameMethods>(
 ) -> sys::error_code {
     let (aux, game) = get_both::<G>(game);
     let str_buf = &mut aux.str_buf;
-    *str_buf = Default::default();
+    str_buf.clear();
     surena_try!(aux, game.export_state(player, str_buf));
 
     ret_str.write(str_buf.as_ptr());
@@ -320,34 +1198,54 @@ unsafe extern "C" fn players_to_move_wrapped<G: GameMethods>(
     player_buf.clear();
     surena_try!(aux, game.players_to_move(player_buf));
 
-    players.write(player_buf.as_ptr());
-    ret_count.write(
-        player_buf
-            .len()
-            .try_into()
-            .expect("player buffer too large"),
-    );
+    let count = surena_try!(aux, checked_player_count(aux.player_buf.len()));
+    players.write(aux.player_buf.as_ptr());
+    ret_count.write(count);
     sys::ERR_ERR_OK
 }
 
-unsafe extern "C" fn get_concrete_moves_wrapped<G: GameMethods>(
+unsafe extern "C" fn get_concrete_moves_wrapped<G: GameMethods, const CACHE: bool>(
     game: *mut sys::game,
     player: player_id,
     ret_count: *mut u32,
     moves: *mut *const move_data,
 ) -> sys::error_code {
     let (aux, game) = get_both::<G>(game);
-    let move_buf = &mut aux.move_buf;
-    move_buf.clear();
-    surena_try!(aux, game.get_concrete_moves(player, move_buf));
+    if !CACHE || aux.move_cache != Some(player) {
+        aux.move_cache = None;
+        aux.move_buf.clear();
+        surena_try!(aux, game.get_concrete_moves(player, &mut aux.move_buf));
+        aux.move_cache = CACHE.then_some(player);
+    }
 
-    let ptr: *const G::Move = move_buf.as_ptr();
+    let count = surena_try!(aux, checked_move_count(aux.move_buf.len()));
+    let ptr: *const G::Move = aux.move_buf.as_ptr();
     moves.write(ptr.cast::<move_data>());
-    ret_count.write(move_buf.len().try_into().expect("move buffer too long"));
+    ret_count.write(count);
+    sys::ERR_ERR_OK
+}
+
+unsafe extern "C" fn get_concrete_moves_pooled_wrapped<G: GameMethods>(
+    game: *mut sys::game,
+    player: player_id,
+    ret_count: *mut u32,
+    moves: *mut *const move_data,
+) -> sys::error_code {
+    let (aux, game) = get_both::<G>(game);
+    let move_builder = &mut aux.move_builder;
+    move_builder.clear();
+    surena_try!(aux, game.get_concrete_moves_pooled(player, move_builder));
+
+    let count = surena_try!(aux, checked_move_count(aux.move_builder.len()));
+    moves.write(aux.move_builder.finish().as_ptr());
+    ret_count.write(count);
     sys::ERR_ERR_OK
 }
 
-unsafe extern "C" fn get_concrete_move_probabilities_wrapped<G: GameMethods>(
+unsafe extern "C" fn get_concrete_move_probabilities_wrapped<
+    G: GameMethods,
+    const NORMALIZE: bool,
+>(
     game: *mut sys::game,
     ret_count: *mut u32,
     ret_move_probabilities: *mut *const c_float,
@@ -356,25 +1254,23 @@ unsafe extern "C" fn get_concrete_move_probabilities_wrapped<G: GameMethods>(
     let prob_buf = &mut aux.float_buf;
     prob_buf.clear();
     surena_try!(aux, game.get_concrete_move_probabilities(prob_buf));
+    if NORMALIZE {
+        surena_try!(aux, normalize_probabilities(&mut aux.float_buf));
+    }
 
-    ret_move_probabilities.write(prob_buf.as_ptr());
-    ret_count.write(
-        prob_buf
-            .len()
-            .try_into()
-            .expect("probability buffer too large"),
-    );
+    let count = surena_try!(aux, checked_move_count(aux.float_buf.len()));
+    ret_move_probabilities.write(aux.float_buf.as_ptr());
+    ret_count.write(count);
     sys::ERR_ERR_OK
 }
 
 unsafe extern "C" fn get_random_move_wrapped<G: GameMethods>(
     game: *mut sys::game,
-    // TODO: Maybe use RNG here?
     seed: u64,
     ret_move: *mut *mut move_data_sync,
 ) -> sys::error_code {
     let (aux, game_data) = get_both::<G>(game);
-    let result = surena_try!(aux, game_data.get_random_move(seed));
+    let result = surena_try!(aux, game_data.get_random_move(&mut GameRng::new(seed)));
     aux.sync_buf = MoveDataSync {
         md: result,
         sync_ctr: *addr_of!((*game).sync_ctr),
@@ -391,13 +1287,15 @@ unsafe extern "C" fn get_actions_wrapped<G: GameMethods>(
     moves: *mut *const move_data,
 ) -> sys::error_code {
     let (aux, game) = get_both::<G>(game);
+    aux.move_cache = None;
     let move_buf = &mut aux.move_buf;
     move_buf.clear();
     surena_try!(aux, game.get_actions(player, move_buf));
 
-    let ptr: *const G::Move = move_buf.as_ptr();
+    let count = surena_try!(aux, checked_move_count(aux.move_buf.len()));
+    let ptr: *const G::Move = aux.move_buf.as_ptr();
     moves.write(ptr.cast::<move_data>());
-    ret_count.write(move_buf.len().try_into().expect("move buffer too long"));
+    ret_count.write(count);
 
     sys::ERR_ERR_OK
 }
@@ -429,19 +1327,31 @@ unsafe extern "C" fn is_legal_move_wrapped<G: GameMethods>(
     player: player_id,
     mov: move_data_sync,
 ) -> sys::error_code {
-    let (aux, game) = get_both::<G>(game);
-    surena_try!(aux, game.is_legal_move(player, new_sync::<G::Move>(&mov)));
+    let (aux, game_data) = get_both::<G>(game);
+    game_data.set_sync_ctr(*addr_of!((*game).sync_ctr));
+    surena_try!(
+        aux,
+        game_data.is_legal_move(player, new_sync::<G::Move>(&mov))
+    );
 
     sys::ERR_ERR_OK
 }
 
-unsafe extern "C" fn make_move_wrapped<G: GameMethods>(
+unsafe extern "C" fn make_move_wrapped<G: GameMethods, const CHECK: bool>(
     game: *mut sys::game,
     player: player_id,
     mov: move_data_sync,
 ) -> sys::error_code {
-    let (aux, game) = get_both::<G>(game);
-    surena_try!(aux, game.make_move(player, new_sync::<G::Move>(&mov)));
+    let (aux, game_data) = get_both::<G>(game);
+    game_data.set_sync_ctr(*addr_of!((*game).sync_ctr));
+    if CHECK {
+        surena_try!(
+            aux,
+            game_data.is_legal_move(player, new_sync::<G::Move>(&mov))
+        );
+    }
+    aux.move_cache = None;
+    surena_try!(aux, game_data.make_move(player, new_sync::<G::Move>(&mov)));
 
     sys::ERR_ERR_OK
 }
@@ -456,13 +1366,9 @@ unsafe extern "C" fn get_results_wrapped<G: GameMethods>(
     player_buf.clear();
     surena_try!(aux, game.get_results(player_buf));
 
-    players.write(player_buf.as_ptr());
-    ret_count.write(
-        player_buf
-            .len()
-            .try_into()
-            .expect("player buffer too large"),
-    );
+    let count = surena_try!(aux, checked_player_count(aux.player_buf.len()));
+    players.write(aux.player_buf.as_ptr());
+    ret_count.write(count);
     sys::ERR_ERR_OK
 }
 
@@ -472,7 +1378,8 @@ unsafe extern "C" fn redact_keep_state_wrapped<G: GameMethods>(
     players: *const player_id,
 ) -> sys::error_code {
     let (aux, game) = get_both::<G>(game);
-    let players = from_raw_hedged(players, count.into());
+    aux.move_cache = None;
+    let players = slice_from_raw_hedged(players, count.into());
     surena_try!(aux, game.redact_keep_state(players));
 
     sys::ERR_ERR_OK
@@ -485,8 +1392,8 @@ unsafe extern "C" fn get_move_data_wrapped<G: GameMethods>(
     ret_move: *mut *mut move_data_sync,
 ) -> sys::error_code {
     let (aux, game_data) = get_both::<G>(game);
-    let string = cstr_to_rust_unchecked(string);
-    let result = surena_try!(aux, game_data.get_move_data(player, string));
+    let bytes = cstr_to_bytes(string).unwrap_or_default();
+    let result = surena_try!(aux, game_data.get_move_data_bytes(player, bytes));
     aux.sync_buf = MoveDataSync {
         md: result,
         sync_ctr: *addr_of!((*game).sync_ctr),
@@ -505,7 +1412,7 @@ unsafe extern "C" fn get_move_str_wrapped<G: GameMethods>(
 ) -> sys::error_code {
     let (aux, game) = get_both::<G>(game);
     let str_buf = &mut aux.str_buf;
-    *str_buf = Default::default();
+    str_buf.clear();
     surena_try!(
         aux,
         game.get_move_str(player, new_sync::<G::Move>(&mov), str_buf)
@@ -524,7 +1431,7 @@ unsafe extern "C" fn print_wrapped<G: GameMethods>(
 ) -> sys::error_code {
     let (aux, game) = get_both::<G>(game);
     let str_buf = &mut aux.str_buf;
-    *str_buf = Default::default();
+    str_buf.clear();
     surena_try!(aux, game.print(player, str_buf));
 
     ret_str.write(str_buf.as_ptr());
@@ -532,6 +1439,77 @@ unsafe extern "C" fn print_wrapped<G: GameMethods>(
     sys::ERR_ERR_OK
 }
 
+unsafe extern "C" fn id_wrapped<G: GameMethods>(
+    game: *mut sys::game,
+    ret_id: *mut u64,
+) -> sys::error_code {
+    let (aux, game) = get_both::<G>(game);
+    ret_id.write(surena_try!(aux, game.id()));
+
+    sys::ERR_ERR_OK
+}
+
+unsafe extern "C" fn serialize_wrapped<G: GameMethods>(
+    game: *mut sys::game,
+    player: player_id,
+    ret_blob: *mut *const sys::blob,
+) -> sys::error_code {
+    let (aux, game) = get_both::<G>(game);
+    let byte_buf = &mut aux.byte_buf;
+    byte_buf.clear();
+    surena_try!(aux, game.serialize(player, byte_buf));
+
+    aux.blob_buf = sys::blob {
+        data: aux.byte_buf.as_mut_ptr().cast(),
+        len: aux.byte_buf.len(),
+    };
+    ret_blob.write(&aux.blob_buf);
+    sys::ERR_ERR_OK
+}
+
+/// Convert the length of a player buffer to the count type of _surena_.
+fn checked_player_count(len: usize) -> Result<u8> {
+    len.try_into()
+        .map_err(|_| Error::new_static(ErrorCode::InvalidPlayer, "too many players\0"))
+}
+
+/// Convert the length of a move buffer to the count type of _surena_.
+fn checked_move_count(len: usize) -> Result<u32> {
+    len.try_into()
+        .map_err(|_| Error::new_static(ErrorCode::InvalidState, "too many moves\0"))
+}
+
+/// Extension trait for player buffers like in
+/// [`GameMethods::players_to_move()`].
+///
+/// _surena_ can only handle up to [`u8::MAX`] players per buffer.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::*, game::*};
+/// let mut players = vec![];
+/// for player in 1..=255 {
+///     players.try_push(player).unwrap();
+/// }
+/// let error = players.try_push(1).unwrap_err();
+/// assert_eq!(ErrorCode::InvalidPlayer, error.code);
+/// assert_eq!(255, players.len());
+/// ```
+pub trait PlayerSink {
+    /// Push `player` or fail with [`ErrorCode::InvalidPlayer`] if the buffer
+    /// is full.
+    fn try_push(&mut self, player: player_id) -> Result<()>;
+}
+
+impl PlayerSink for Vec<player_id> {
+    #[inline]
+    fn try_push(&mut self, player: player_id) -> Result<()> {
+        checked_player_count(self.len() + 1)?;
+        self.push(player);
+        Ok(())
+    }
+}
+
 /// Trait for wrappers of owned [`move_data`].
 ///
 /// # Safety
@@ -554,6 +1532,47 @@ pub unsafe trait MoveData: Default {
     fn to_rust(&self) -> Self::Rust<'_>;
 }
 
+/// Extension trait for filling move buffers from iterators.
+///
+/// # Example
+/// ```
+/// # use mirabel::game::*;
+/// let mut manual: Vec<MoveCode> = Vec::new();
+/// for mov in 1..=3 {
+///     manual.push(MoveCode::from(mov));
+/// }
+///
+/// let mut moves: Vec<MoveCode> = Vec::new();
+/// moves.extend_codes(1..=3);
+/// assert_eq!(MoveCode::slice_to_rust(&manual), MoveCode::slice_to_rust(&moves));
+/// ```
+pub trait MoveSink<M: MoveData> {
+    /// Append moves created from [`move_code`]s.
+    fn extend_codes<I: IntoIterator<Item = move_code>>(&mut self, iter: I)
+    where
+        M: From<move_code>;
+
+    /// Append moves converted from arbitrary values.
+    fn extend_moves<T: Into<M>, I: IntoIterator<Item = T>>(&mut self, iter: I);
+}
+
+impl<M: MoveData> MoveSink<M> for Vec<M> {
+    fn extend_codes<I: IntoIterator<Item = move_code>>(&mut self, iter: I)
+    where
+        M: From<move_code>,
+    {
+        self.extend_moves(iter);
+    }
+
+    fn extend_moves<T: Into<M>, I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for mov in iter {
+            self.push(mov.into());
+        }
+    }
+}
+
 /// [`move_data`] which is known to represent an owned move code.
 #[repr(transparent)]
 #[derive(Clone, Copy)]
@@ -566,6 +1585,49 @@ impl MoveCode {
     pub fn slice_to_rust(s: &[Self]) -> Vec<move_code> {
         s.iter().map(|&m| m.into()).collect()
     }
+
+    /// Create a new vector of [`Self`] from a slice of [`move_code`]s.
+    ///
+    /// This is the inverse of [`Self::slice_to_rust()`].
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::game::MoveCode;
+    /// let moves = MoveCode::slice_from_codes(&[1, 2, 3]);
+    /// assert_eq!(vec![1, 2, 3], MoveCode::slice_to_rust(&moves));
+    /// ```
+    pub fn slice_from_codes(codes: &[move_code]) -> Vec<Self> {
+        codes.iter().map(|&c| c.into()).collect()
+    }
+
+    /// Convert `mov` into a [`MoveCode`] if it is not a big move.
+    ///
+    /// Unlike [`MoveData::from_ref()`], big moves are rejected with
+    /// [`ErrorCode::InvalidInput`].
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::ErrorCode, game::{move_code, MoveCode}};
+    /// let code = *MoveCode::from(7);
+    /// let mov = MoveCode::try_from_move_data(&code).unwrap();
+    /// assert_eq!(7, move_code::from(mov));
+    ///
+    /// let mut bytes = [1u8, 2];
+    /// let mut big = code;
+    /// big.cl.len = bytes.len();
+    /// big.data = bytes.as_mut_ptr();
+    /// let error = MoveCode::try_from_move_data(&big).unwrap_err();
+    /// assert_eq!(ErrorCode::InvalidInput, error.code);
+    /// ```
+    pub fn try_from_move_data(mov: &move_data) -> Result<Self> {
+        if !mov.data.is_null() {
+            return Err(Error::new_static(
+                ErrorCode::InvalidInput,
+                "expected move code but got big move\0",
+            ));
+        }
+        Ok(Self(*mov))
+    }
 }
 
 unsafe impl MoveData for MoveCode {
@@ -595,7 +1657,10 @@ impl Deref for MoveCode {
 
 impl From<MoveCode> for move_code {
     fn from(value: MoveCode) -> Self {
-        unsafe { value.cl.code }
+        match unsafe { move_data_view(&value) } {
+            MoveDataView::Code(code) => code,
+            MoveDataView::Big(_) => unreachable!("move code with data"),
+        }
     }
 }
 
@@ -609,6 +1674,21 @@ impl From<move_code> for MoveCode {
     }
 }
 
+impl PartialEq for MoveCode {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_rust() == other.to_rust()
+    }
+}
+
+impl Eq for MoveCode {}
+
+impl fmt::Debug for MoveCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_move_code(f, self.to_rust())
+    }
+}
+
 impl Default for MoveCode {
     fn default() -> Self {
         0.into()
@@ -619,6 +1699,12 @@ impl Default for MoveCode {
 #[repr(transparent)]
 pub struct MixedMove(move_data);
 
+/// Number of big move buffers allocated by [`MixedMove`] and not yet dropped.
+///
+/// See [`crate::testing::assert_no_leaked_moves()`].
+#[cfg(debug_assertions)]
+pub(crate) static LIVE_BIG_MOVES: AtomicIsize = AtomicIsize::new(0);
+
 unsafe impl MoveData for MixedMove {
     type Rust<'l> = MixedMoveRust<'l>;
     const BIG_MOVES: bool = true;
@@ -629,13 +1715,9 @@ unsafe impl MoveData for MixedMove {
     }
 
     fn to_rust(&self) -> Self::Rust<'_> {
-        unsafe {
-            if self.data.is_null() {
-                MixedMoveRust::MoveCode(self.cl.code)
-            } else {
-                // len==0 for empty big moves.
-                MixedMoveRust::BigMove(from_raw_hedged(self.data, self.cl.len))
-            }
+        match unsafe { move_data_view(self) } {
+            MoveDataView::Code(code) => MixedMoveRust::MoveCode(code),
+            MoveDataView::Big(bytes) => MixedMoveRust::BigMove(bytes),
         }
     }
 }
@@ -649,11 +1731,92 @@ impl Deref for MixedMove {
     }
 }
 
+/// Maximum length of a big move in bytes.
+///
+/// Hosts may store the length of big moves in 32 bits.
+pub const MAX_BIG_MOVE_LEN: usize = u32::MAX as usize;
+
+impl MixedMove {
+    /// Returns the bytes of a big move or [`None`] for a move code.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::game::MixedMove;
+    /// assert_eq!(None, MixedMove::from(42).as_bytes());
+    /// assert_eq!(Some(&[][..]), MixedMove::try_from(&[][..]).unwrap().as_bytes());
+    /// assert_eq!(
+    ///     Some(&[1, 2, 3][..]),
+    ///     MixedMove::try_from(&[1, 2, 3][..]).unwrap().as_bytes(),
+    /// );
+    /// ```
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self.to_rust() {
+            MixedMoveRust::MoveCode(_) => None,
+            MixedMoveRust::BigMove(bytes) => Some(bytes),
+        }
+    }
+
+    /// Copies `bytes` into a new big move.
+    ///
+    /// Use this instead of [`From<Vec<u8>>`] for untrusted input, e.g., from
+    /// the network.
+    /// Fails with [`ErrorCode::InvalidInput`] if `bytes` is longer than
+    /// [`MAX_BIG_MOVE_LEN`].
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::game::MixedMove;
+    /// let mov = MixedMove::try_from_bytes(&[4, 2]).unwrap();
+    /// assert_eq!(Some(&[4, 2][..]), mov.as_bytes());
+    /// ```
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::check_len(bytes.len())?;
+        Ok(bytes.to_vec().into())
+    }
+
+    /// Checks whether a big move of `len` bytes is acceptable.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::ErrorCode, game::{MixedMove, MAX_BIG_MOVE_LEN}};
+    /// assert!(MixedMove::check_len(MAX_BIG_MOVE_LEN).is_ok());
+    /// if let Some(len) = MAX_BIG_MOVE_LEN.checked_add(1) {
+    ///     let error = MixedMove::check_len(len).unwrap_err();
+    ///     assert_eq!(ErrorCode::InvalidInput, error.code);
+    /// }
+    /// ```
+    pub fn check_len(len: usize) -> Result<()> {
+        if len > MAX_BIG_MOVE_LEN {
+            return Err(Error::new_static(
+                ErrorCode::InvalidInput,
+                "big move too long\0",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&[u8]> for MixedMove {
+    type Error = Error;
+
+    /// See [`MixedMove::try_from_bytes()`].
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self> {
+        Self::try_from_bytes(value)
+    }
+}
+
+/// Infallible conversion for trusted callers.
+///
+/// This does not check the length against [`MAX_BIG_MOVE_LEN`], see
+/// [`MixedMove::try_from_bytes()`].
 impl From<Vec<u8>> for MixedMove {
     fn from(value: Vec<u8>) -> Self {
         // Empty big moves must have data!=NULL, which is the case for slice
         // pointers.
         let slice = Box::leak(value.into_boxed_slice());
+        #[cfg(debug_assertions)]
+        LIVE_BIG_MOVES.fetch_add(1, Ordering::Relaxed);
         Self(move_data {
             cl: move_data_cl { len: slice.len() },
             data: slice.as_mut_ptr(),
@@ -670,6 +1833,21 @@ impl From<move_code> for MixedMove {
     }
 }
 
+impl PartialEq for MixedMove {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_rust() == other.to_rust()
+    }
+}
+
+impl Eq for MixedMove {}
+
+impl fmt::Debug for MixedMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_rust(), f)
+    }
+}
+
 impl Default for MixedMove {
     fn default() -> Self {
         0.into()
@@ -683,17 +1861,130 @@ impl Drop for MixedMove {
             let boxed: Box<[u8]> =
                 unsafe { Box::from_raw(from_raw_parts_mut(self.data, self.cl.len)) };
             drop(boxed);
+            #[cfg(debug_assertions)]
+            LIVE_BIG_MOVES.fetch_sub(1, Ordering::Relaxed);
         }
     }
 }
 
 /// Enum for a borrowed [`move_data`] which could be a [`move_code`] or big
 /// move.
+///
+/// # Example
+/// ```
+/// # use mirabel::game::MixedMoveRust;
+/// assert_eq!("MoveCode(0x2a)", format!("{:?}", MixedMoveRust::MoveCode(42)));
+/// assert_eq!(
+///     "BigMove([len 4] 01 02 03 ff)",
+///     format!("{:?}", MixedMoveRust::BigMove(&[1, 2, 3, 255])),
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum MixedMoveRust<'l> {
     MoveCode(move_code),
     BigMove(&'l [u8]),
 }
 
+impl<'l> fmt::Debug for MixedMoveRust<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MoveCode(code) => debug_move_code(f, *code),
+            Self::BigMove(bytes) => debug_big_move(f, bytes),
+        }
+    }
+}
+
+/// Buffer for emitting moves without allocating every big move separately.
+///
+/// The bytes of all big moves are appended to a single arena owned by the
+/// wrapper.
+/// The buffer is cleared, but keeps its capacity, at the start of every
+/// [`GameMethods::get_concrete_moves_pooled()`] call.
+/// The [`move_data`] handed to _surena_ point into this arena and remain valid
+/// until the next `get_concrete_moves` or `destroy` call on the same game.
+///
+/// # Example
+/// ```
+/// # use mirabel::game::*;
+/// let mut moves = MoveBuilder::default();
+/// moves.push_big(b"e2e4");
+/// moves.push_big(b"");
+/// moves.push_code(42);
+/// assert_eq!(3, moves.len());
+/// ```
+#[derive(Default)]
+pub struct MoveBuilder {
+    bytes: Vec<u8>,
+    entries: Vec<MoveEntry>,
+    moves: Vec<move_data>,
+}
+
+/// A move inside a [`MoveBuilder`].
+enum MoveEntry {
+    Code(move_code),
+    /// Start and end of the move in [`MoveBuilder::bytes`].
+    Big(usize, usize),
+}
+
+impl MoveBuilder {
+    /// Append a [`move_code`].
+    #[inline]
+    pub fn push_code(&mut self, code: move_code) {
+        self.entries.push(MoveEntry::Code(code));
+    }
+
+    /// Append a big move by copying `bytes` into the arena.
+    #[inline]
+    pub fn push_big(&mut self, bytes: &[u8]) {
+        let start = self.bytes.len();
+        self.bytes.extend_from_slice(bytes);
+        self.entries.push(MoveEntry::Big(start, self.bytes.len()));
+    }
+
+    /// Number of moves pushed.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.bytes.clear();
+        self.entries.clear();
+        self.moves.clear();
+    }
+
+    /// Create the [`move_data`] pointing into the arena.
+    ///
+    /// This must only be called after all moves have been pushed because
+    /// pushing might reallocate the arena.
+    fn finish(&mut self) -> &[move_data] {
+        self.moves.clear();
+        for entry in &self.entries {
+            self.moves.push(match *entry {
+                MoveEntry::Code(code) => move_data {
+                    cl: move_data_cl { code },
+                    data: null_mut(),
+                },
+                MoveEntry::Big(start, end) => {
+                    let slice = &self.bytes[start..end];
+                    move_data {
+                        cl: move_data_cl { len: slice.len() },
+                        // Slice pointers are never NULL as required for big
+                        // moves. surena only reads the data.
+                        data: slice.as_ptr().cast_mut(),
+                    }
+                }
+            });
+        }
+        &self.moves
+    }
+}
+
 /// Create a new, borrowed [`MoveDataSync`] from a [`move_data_sync`].
 ///
 /// This only shallow-copies the [`sync_ctr`](move_data_sync::sync_ctr) and the
@@ -722,14 +2013,11 @@ fn new_sync<M: MoveData>(mov: &move_data_sync) -> MoveDataSync<M::Rust<'_>> {
 ///     game_name: cstr("Example\0"),
 ///     variant_name: cstr("Standard\0"),
 ///     impl_name: cstr("mirabel_rs\0"),
-///     version: semver {
-///         major: 0,
-///         minor: 1,
-///         patch: 0,
-///     },
+///     version: semver::new(0, 1, 0),
 ///     features,
 /// };
 /// ```
+#[derive(PartialEq, Eq, Debug)]
 pub struct Metadata {
     pub game_name: ValidCStr<'static>,
     pub variant_name: ValidCStr<'static>,
@@ -738,15 +2026,337 @@ pub struct Metadata {
     pub features: GameFeatures,
 }
 
-/// Optional game features which are supported by this wrapper.
+impl Metadata {
+    /// Create a [`MetadataBuilder`] which does not require NUL-terminated
+    /// names.
+    #[inline]
+    pub fn builder() -> MetadataBuilder {
+        Default::default()
+    }
+}
+
+/// Builder for [`Metadata`].
+///
+/// The names are copied into [`ValidCString`]s which are leaked because
+/// [`Metadata`] requires `'static` strings.
+/// This is fine because the [`game_methods`] live for the whole lifetime of
+/// the plugin anyway.
+///
+/// # Example
+/// ```
+/// # use mirabel::{cstr, game::*};
+/// let features = GameFeatures {
+///     print: true,
+///     ..Default::default()
+/// };
+///
+/// let built = Metadata::builder()
+///     .game_name("Example")
+///     .variant_name("Standard")
+///     .impl_name("mirabel_rs")
+///     .version(0, 1, 0)
+///     .features(features)
+///     .build();
 ///
-/// Subset of [`game_feature_flags`].
+/// let literal = Metadata {
+///     game_name: cstr("Example\0"),
+///     variant_name: cstr("Standard\0"),
+///     impl_name: cstr("mirabel_rs\0"),
+///     version: semver::new(0, 1, 0),
+///     features: GameFeatures {
+///         print: true,
+///         ..Default::default()
+///     },
+/// };
+/// assert_eq!(literal, built);
+/// ```
 #[derive(Default)]
+pub struct MetadataBuilder {
+    game_name: Option<ValidCStr<'static>>,
+    variant_name: Option<ValidCStr<'static>>,
+    impl_name: Option<ValidCStr<'static>>,
+    version: semver,
+    features: GameFeatures,
+}
+
+impl MetadataBuilder {
+    /// # Panics
+    /// Panics if `name` contains a NUL byte.
+    pub fn game_name(mut self, name: &str) -> Self {
+        self.game_name = Some(leak_name(name));
+        self
+    }
+
+    /// # Panics
+    /// Panics if `name` contains a NUL byte.
+    pub fn variant_name(mut self, name: &str) -> Self {
+        self.variant_name = Some(leak_name(name));
+        self
+    }
+
+    /// # Panics
+    /// Panics if `name` contains a NUL byte.
+    pub fn impl_name(mut self, name: &str) -> Self {
+        self.impl_name = Some(leak_name(name));
+        self
+    }
+
+    pub fn version(mut self, major: u32, minor: u32, patch: u32) -> Self {
+        self.version = semver::new(major, minor, patch);
+        self
+    }
+
+    pub fn features(mut self, features: GameFeatures) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Create the [`Metadata`].
+    ///
+    /// # Panics
+    /// Panics if one of the names was not set.
+    pub fn build(self) -> Metadata {
+        Metadata {
+            game_name: self.game_name.expect("game_name not set"),
+            variant_name: self.variant_name.expect("variant_name not set"),
+            impl_name: self.impl_name.expect("impl_name not set"),
+            version: self.version,
+            features: self.features,
+        }
+    }
+}
+
+/// Scale `probabilities` so that they sum up to one.
+///
+/// Fails with [`ErrorCode::InvalidState`] if any probability is negative or
+/// not finite, or if all of them are zero.
+/// An empty slice is left untouched.
+///
+/// # Example
+/// ```
+/// # use mirabel::game::normalize_probabilities;
+/// let mut probabilities = [1.0, 3.0];
+/// normalize_probabilities(&mut probabilities).unwrap();
+/// assert_eq!([0.25, 0.75], probabilities);
+///
+/// assert!(normalize_probabilities(&mut [0.0, 0.0]).is_err());
+/// assert!(normalize_probabilities(&mut [1.0, -1.0]).is_err());
+/// ```
+pub fn normalize_probabilities(probabilities: &mut [c_float]) -> Result<()> {
+    if probabilities.is_empty() {
+        return Ok(());
+    }
+    if probabilities.iter().any(|p| !p.is_finite() || *p < 0.0) {
+        return Err(Error::new_static(
+            ErrorCode::InvalidState,
+            "move probabilities must be finite and non-negative\0",
+        ));
+    }
+    let sum: c_float = probabilities.iter().sum();
+    if sum <= 0.0 || !sum.is_finite() {
+        return Err(Error::new_static(
+            ErrorCode::InvalidState,
+            "move probabilities sum up to zero\0",
+        ));
+    }
+    for p in probabilities {
+        *p /= sum;
+    }
+    Ok(())
+}
+
+/// Copy `name` into a leaked [`ValidCString`].
+fn leak_name(name: &str) -> ValidCStr<'static> {
+    let name: ValidCString = name.to_string().try_into().expect("name contains NUL byte");
+    let name: &'static ValidCString = Box::leak(Box::new(name));
+    name.into()
+}
+
+/// List the enabled `flags` by name, e.g., for diagnostics.
+///
+/// Returns `"none"` if no flag is set.
+///
+/// # Example
+/// ```
+/// # use mirabel::{game::features_summary, sys::game_feature_flags};
+/// let mut flags = game_feature_flags::default();
+/// assert_eq!("none", features_summary(&flags));
+/// flags.set_options(true);
+/// flags.set_print(true);
+/// assert_eq!("options, print", features_summary(&flags));
+/// ```
+pub fn features_summary(flags: &game_feature_flags) -> String {
+    let named: [(&str, fn(&game_feature_flags) -> bool); 8] = [
+        ("error_strings", game_feature_flags::error_strings),
+        ("options", game_feature_flags::options),
+        ("serializable", game_feature_flags::serializable),
+        ("random_moves", game_feature_flags::random_moves),
+        ("hidden_information", game_feature_flags::hidden_information),
+        ("big_moves", game_feature_flags::big_moves),
+        ("print", game_feature_flags::print),
+        ("id", game_feature_flags::id),
+    ];
+    let enabled: Vec<_> = named
+        .iter()
+        .filter(|(_, get)| get(flags))
+        .map(|&(name, _)| name)
+        .collect();
+    if enabled.is_empty() {
+        "none".to_owned()
+    } else {
+        enabled.join(", ")
+    }
+}
+
+/// Optional game features which are supported by this wrapper.
+///
+/// Subset of [`game_feature_flags`] plus some options which only affect the
+/// wrapper.
+///
+/// Enabled features require the game to implement these methods:
+///
+/// | Feature              | Required methods                                   |
+/// |----------------------|----------------------------------------------------|
+/// | `options`            | `export_options`                                   |
+/// | `random_moves`       | `get_concrete_move_probabilities`,                 |
+/// |                      | `get_random_move`, `redact_keep_state`             |
+/// | `hidden_information` | `get_actions`, `move_to_action`,                   |
+/// |                      | `redact_keep_state`                                |
+/// | `print`              | `print`                                            |
+/// | `debug_print`        | `debug_print`                                      |
+/// | `serializable`       | `serialize`                                        |
+/// | `id`                 | `id`                                               |
+/// | `pooled_moves`       | `get_concrete_moves_pooled`                        |
+#[derive(Default, PartialEq, Eq, Debug)]
 pub struct GameFeatures {
     pub options: bool,
     pub random_moves: bool,
     pub hidden_information: bool,
     pub print: bool,
+    /// Use [`GameMethods::get_concrete_moves_pooled()`] instead of
+    /// [`GameMethods::get_concrete_moves()`].
+    ///
+    /// Requires [`MixedMove`]s.
+    pub pooled_moves: bool,
+    /// Pass the results of [`GameMethods::get_concrete_move_probabilities()`]
+    /// through [`normalize_probabilities()`].
+    ///
+    /// Requires [`Self::random_moves`].
+    pub auto_normalize: bool,
+    /// Include [`GameMethods::debug_print()`] in
+    /// [`snapshot()`](crate::testing::snapshot)s.
+    ///
+    /// Requires [`Self::print`].
+    pub debug_print: bool,
+    pub serializable: bool,
+    pub id: bool,
+    /// Reuse the moves of [`GameMethods::get_concrete_moves()`] for repeated
+    /// queries of the same player.
+    ///
+    /// The cache is dropped on every state change through the API.
+    /// Hence, the moves must only depend on the state and the player.
+    /// Cannot be combined with [`Self::pooled_moves`].
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, testing::Countdown, *};
+    /// let methods = create_game_methods::<Countdown>(
+    ///     Metadata::builder()
+    ///         .game_name("Countdown")
+    ///         .variant_name("Standard")
+    ///         .impl_name("mirabel_rs")
+    ///         .features(GameFeatures {
+    ///             move_cache: true,
+    ///             ..Default::default()
+    ///         })
+    ///         .build(),
+    /// );
+    /// let mut game = sys::game {
+    ///     methods: &methods,
+    ///     ..Default::default()
+    /// };
+    /// let mut init = sys::game_init {
+    ///     source_type: sys::GAME_INIT_SOURCE_TYPE_E_GAME_INIT_SOURCE_TYPE_DEFAULT,
+    ///     ..Default::default()
+    /// };
+    /// let get_concrete_moves = methods.get_concrete_moves.unwrap();
+    /// let (mut count, mut moves) = (0, std::ptr::null());
+    /// unsafe {
+    ///     methods.create.unwrap()(&mut game, &mut init);
+    ///     for _ in 0..2 {
+    ///         get_concrete_moves(&mut game, 1, &mut count, &mut moves);
+    ///         assert_eq!(3, count);
+    ///     }
+    ///
+    ///     // The cached moves are never stale after a move.
+    ///     let mov = sys::move_data_sync {
+    ///         md: *MoveCode::from(8),
+    ///         sync_ctr: game.sync_ctr,
+    ///     };
+    ///     methods.make_move.unwrap()(&mut game, 1, mov);
+    ///     get_concrete_moves(&mut game, 1, &mut count, &mut moves);
+    ///     assert_eq!(2, count);
+    ///     methods.destroy.unwrap()(&mut game);
+    /// }
+    /// ```
+    pub move_cache: bool,
+    /// Call [`GameMethods::is_legal_move()`] before every
+    /// [`GameMethods::make_move()`] and reject illegal moves.
+    ///
+    /// This doubles the work per move for games which already validate in
+    /// [`GameMethods::make_move()`].
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, testing::Countdown, *};
+    /// # use std::{ffi::CStr, ptr::null};
+    /// let methods = try_create_game_methods::<Countdown>(
+    ///     Metadata::builder()
+    ///         .game_name("Countdown")
+    ///         .variant_name("Standard")
+    ///         .impl_name("mirabel_rs")
+    ///         .features(GameFeatures {
+    ///             check_before_move: true,
+    ///             ..Default::default()
+    ///         })
+    ///         .build(),
+    /// )
+    /// .unwrap();
+    /// let mut game = sys::game {
+    ///     methods: &methods,
+    ///     ..Default::default()
+    /// };
+    /// let mut init = sys::game_init {
+    ///     source_type: sys::GAME_INIT_SOURCE_TYPE_E_GAME_INIT_SOURCE_TYPE_DEFAULT,
+    ///     ..Default::default()
+    /// };
+    /// let make_move = methods.make_move.unwrap();
+    /// unsafe fn state(game: &mut sys::game) -> String {
+    ///     let (mut size, mut state) = (0, null());
+    ///     (*game.methods).export_state.unwrap()(game, 1, &mut size, &mut state);
+    ///     CStr::from_ptr(state).to_str().unwrap().to_owned()
+    /// }
+    /// unsafe {
+    ///     methods.create.unwrap()(&mut game, &mut init);
+    ///
+    ///     // Rejected before make_move() could underflow the counter.
+    ///     let illegal = sys::move_data_sync {
+    ///         md: *MoveCode::from(11),
+    ///         sync_ctr: game.sync_ctr,
+    ///     };
+    ///     assert_eq!(sys::ERR_ERR_INVALID_MOVE, make_move(&mut game, 1, illegal));
+    ///     assert_eq!("10", state(&mut game));
+    ///
+    ///     let legal = sys::move_data_sync {
+    ///         md: *MoveCode::from(2),
+    ///         sync_ctr: game.sync_ctr,
+    ///     };
+    ///     assert_eq!(sys::ERR_ERR_OK, make_move(&mut game, 1, legal));
+    ///     assert_eq!("8", state(&mut game));
+    ///     methods.destroy.unwrap()(&mut game);
+    /// }
+    /// ```
+    pub check_before_move: bool,
 }
 
 impl GameFeatures {
@@ -757,25 +2367,176 @@ impl GameFeatures {
         flags.set_random_moves(self.random_moves);
         flags.set_hidden_information(self.hidden_information);
         flags.set_print(self.print);
+        flags.set_serializable(self.serializable);
+        flags.set_id(self.id);
         flags
     }
+
+    /// Check that the enabled features are consistent with each other and with
+    /// the move type of `G`.
+    ///
+    /// Whether `G` actually implements the methods required by the enabled
+    /// features (see [`GameFeatures`]) cannot be checked statically.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, testing::Countdown, *};
+    /// let features = GameFeatures {
+    ///     random_moves: true,
+    ///     auto_normalize: true,
+    ///     print: true,
+    ///     debug_print: true,
+    ///     ..Default::default()
+    /// };
+    /// assert!(features.validate::<Countdown>().is_ok());
+    /// ```
+    ///
+    /// Features missing their dependencies are rejected:
+    /// ```
+    /// # use mirabel::{error::*, game::*, game_init::GameInit, testing::Countdown, *};
+    /// let features = GameFeatures {
+    ///     auto_normalize: true,
+    ///     ..Default::default()
+    /// };
+    /// let error = features.validate::<Countdown>().unwrap_err();
+    /// assert_eq!(ErrorCode::FeatureUnsupported, error.code);
+    ///
+    /// // The pooled moves bypass the move cache.
+    /// let features = GameFeatures {
+    ///     pooled_moves: true,
+    ///     move_cache: true,
+    ///     ..Default::default()
+    /// };
+    /// let error = features.validate::<Countdown>().unwrap_err();
+    /// assert_eq!("pooled_moves cannot use move_cache", error.message.to_string());
+    /// ```
+    pub fn validate<G: GameMethods>(&self) -> Result<()> {
+        if self.pooled_moves && self.move_cache {
+            return Err(Error::new_static(
+                ErrorCode::FeatureUnsupported,
+                "pooled_moves cannot use move_cache\0",
+            ));
+        }
+        if self.pooled_moves && !G::Move::BIG_MOVES {
+            return Err(Error::new_static(
+                ErrorCode::FeatureUnsupported,
+                "pooled_moves requires big moves\0",
+            ));
+        }
+        if self.auto_normalize && !self.random_moves {
+            return Err(Error::new_static(
+                ErrorCode::FeatureUnsupported,
+                "auto_normalize requires random_moves\0",
+            ));
+        }
+        if self.debug_print && !self.print {
+            return Err(Error::new_static(
+                ErrorCode::FeatureUnsupported,
+                "debug_print requires print\0",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Error of the default [`GameMethods::to_move()`].
+#[inline]
+fn to_move_unimplemented() -> Error {
+    Error::new_static(
+        ErrorCode::FeatureUnsupported,
+        "neither to_move nor players_to_move implemented\0",
+    )
+}
+
+thread_local! {
+    /// Reused by [`check_players_to_move()`] so that it does not allocate
+    /// every time.
+    static TO_MOVE_BUF: RefCell<Vec<player_id>> = RefCell::new(vec![]);
+}
+
+/// Default of [`GameMethods::check_to_move()`] and
+/// [`SimpleGame::check_to_move()`].
+fn check_players_to_move(
+    player: player_id,
+    players_to_move: impl FnOnce(&mut Vec<player_id>) -> Result<()>,
+) -> Result<bool> {
+    TO_MOVE_BUF.with(|buf| {
+        // A nested call from players_to_move would panic on borrow_mut.
+        let mut local = vec![];
+        let mut guard = buf.try_borrow_mut();
+        let players = match guard {
+            Ok(ref mut buf) => &mut **buf,
+            Err(_) => &mut local,
+        };
+        players.clear();
+        players_to_move(players)?;
+        if players.is_empty() {
+            Ok(false)
+        } else if players.contains(&player) {
+            Ok(true)
+        } else {
+            Err(Error::new_static(
+                ErrorCode::InvalidInput,
+                "this player is not to move\0",
+            ))
+        }
+    })
 }
 
 /// Create _surena_ [`game_methods`] from game struct `G` and `metadata`.
 ///
 /// If feature flags are disabled, corresponding function pointers will be set
 /// to zero.
+/// This can be called repeatedly for the same `G` with different `metadata`.
+///
+/// # Panics
+/// Panics if [`GameFeatures::validate()`] fails.
 ///
 /// # Example
 /// ```ignore
 /// create_game_methods::<MyGame>(metadata);
 /// ```
 pub fn create_game_methods<G: GameMethods>(metadata: Metadata) -> game_methods {
+    match try_create_game_methods::<G>(metadata) {
+        Ok(methods) => methods,
+        Err(error) => panic!("inconsistent game features: {:?}", error.message),
+    }
+}
+
+/// Like [`create_game_methods()`] but returns an error instead of panicking
+/// if [`GameFeatures::validate()`] fails.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::*, game::*, game_init::GameInit, testing::Countdown, *};
+/// let metadata = Metadata::builder()
+///     .game_name("Countdown")
+///     .variant_name("Standard")
+///     .impl_name("mirabel_rs")
+///     .features(GameFeatures {
+///         debug_print: true,
+///         ..Default::default()
+///     })
+///     .build();
+/// let error = try_create_game_methods::<Countdown>(metadata).unwrap_err();
+/// assert_eq!(ErrorCode::FeatureUnsupported, error.code);
+/// assert!(error.message.to_string().starts_with("Countdown.Standard: "));
+/// ```
+pub fn try_create_game_methods<G: GameMethods>(metadata: Metadata) -> Result<game_methods> {
+    if let Err(error) = metadata.features.validate::<G>() {
+        return Err(error.context(&format!(
+            "{}.{}",
+            <&str>::from(metadata.game_name),
+            <&str>::from(metadata.variant_name),
+        )));
+    }
+
     let mut features = metadata.features.feature_flags();
     features.set_error_strings(true);
     features.set_big_moves(G::Move::BIG_MOVES);
 
-    game_methods {
+    Ok(game_methods {
         game_name: metadata.game_name.into(),
         variant_name: metadata.variant_name.into(),
         impl_name: metadata.impl_name.into(),
@@ -792,29 +2553,125 @@ pub fn create_game_methods<G: GameMethods>(metadata: Metadata) -> game_methods {
         import_state: Some(import_state_wrapped::<G>),
         export_state: Some(export_state_wrapped::<G>),
         players_to_move: Some(players_to_move_wrapped::<G>),
-        get_concrete_moves: Some(get_concrete_moves_wrapped::<G>),
-        get_concrete_move_probabilities: Some(get_concrete_move_probabilities_wrapped::<G>),
-        get_random_move: Some(get_random_move_wrapped::<G>),
+        get_concrete_moves: if metadata.features.pooled_moves {
+            Some(get_concrete_moves_pooled_wrapped::<G>)
+        } else if metadata.features.move_cache {
+            Some(get_concrete_moves_wrapped::<G, true>)
+        } else {
+            Some(get_concrete_moves_wrapped::<G, false>)
+        },
+        get_concrete_move_probabilities: if metadata.features.auto_normalize {
+            Some(get_concrete_move_probabilities_wrapped::<G, true>)
+        } else {
+            Some(get_concrete_move_probabilities_wrapped::<G, false>)
+        },
+        get_random_move: if metadata.features.random_moves {
+            Some(get_random_move_wrapped::<G>)
+        } else {
+            None
+        },
         get_actions: Some(get_actions_wrapped::<G>),
         move_to_action: Some(move_to_action_wrapped::<G>),
         is_legal_move: Some(is_legal_move_wrapped::<G>),
-        make_move: Some(make_move_wrapped::<G>),
+        make_move: if metadata.features.check_before_move {
+            Some(make_move_wrapped::<G, true>)
+        } else {
+            Some(make_move_wrapped::<G, false>)
+        },
         get_results: Some(get_results_wrapped::<G>),
         redact_keep_state: Some(redact_keep_state_wrapped::<G>),
         get_move_data: Some(get_move_data_wrapped::<G>),
         get_move_str: Some(get_move_str_wrapped::<G>),
         print: Some(print_wrapped::<G>),
+        id: Some(id_wrapped::<G>),
+        serialize: Some(serialize_wrapped::<G>),
         ..Default::default()
+    })
+}
+
+/// Used by [`plugin_get_game_methods!()`] for initializing its methods.
+///
+/// The methods of valid games are moved to the front of `methods`, while
+/// invalid games are reported and left out.
+/// Hence, one misconfigured variant does not hide the others.
+/// Returns the number of methods to export.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::*, game::*, game_init::GameInit, testing::Countdown, *};
+/// # use std::ffi::{c_char, CStr};
+/// # #[no_mangle]
+/// # extern "C" fn mirabel_log(_: *const c_char, _: *const c_char) {}
+/// fn variant(name: &str, features: GameFeatures) -> Metadata {
+///     Metadata::builder()
+///         .game_name("Countdown")
+///         .variant_name(name)
+///         .impl_name("mirabel_rs")
+///         .features(features)
+///         .build()
+/// }
+///
+/// let broken = GameFeatures {
+///     debug_print: true,
+///     ..Default::default()
+/// };
+/// let results = [
+///     try_create_game_methods::<Countdown>(variant("Broken", broken)),
+///     try_create_game_methods::<Countdown>(variant("Standard", Default::default())),
+/// ];
+/// let mut methods = Default::default();
+/// assert_eq!(1, init_game_methods(results, &mut methods));
+/// let name = unsafe { CStr::from_ptr(methods[0].variant_name) };
+/// assert_eq!("Standard", name.to_str().unwrap());
+/// ```
+#[doc(hidden)]
+pub fn init_game_methods<const N: usize>(
+    results: [Result<game_methods>; N],
+    methods: &mut [game_methods; N],
+) -> u32 {
+    let mut count = 0;
+    for result in results {
+        match result {
+            Ok(m) => {
+                methods[count] = m;
+                count += 1;
+            }
+            Err(error) => report_invalid_game(&error),
+        }
     }
+    count.try_into().expect("too many game methods")
+}
+
+#[cfg(feature = "mirabel")]
+fn report_invalid_game(error: &Error) {
+    crate::log_error!("inconsistent game features: {}", error.message);
 }
 
+/// Without _mirabel_, there is no log to report to.
+#[cfg(not(feature = "mirabel"))]
+fn report_invalid_game(_error: &Error) {}
+
+/// Per-game data of the wrapper stored in [`data2`](sys::game::data2).
+///
+/// The buffers are only cleared at the start of each call, which keeps their
+/// capacity.
+/// Hence, after warm-up, calls like `players_to_move` do not touch the heap.
 struct Aux<G: GameMethods> {
     str_buf: ValidCString,
+    /// Shared by `players_to_move` and `get_results`.
     player_buf: Vec<player_id>,
     move_buf: Vec<G::Move>,
+    /// Player of the moves in `move_buf` if cached.
+    ///
+    /// Every wrapper which changes the game state resets this.
+    move_cache: Option<player_id>,
+    move_builder: MoveBuilder,
     /// Might get modified from the outside.
     sync_buf: MoveDataSync<G::Move>,
     float_buf: Vec<c_float>,
+    byte_buf: Vec<u8>,
+    /// Points into `byte_buf`.
+    blob_buf: sys::blob,
     error: ErrorString,
 }
 
@@ -849,8 +2706,12 @@ impl<G: GameMethods> Default for Aux<G> {
             str_buf: Default::default(),
             player_buf: Default::default(),
             move_buf: Default::default(),
+            move_cache: Default::default(),
+            move_builder: Default::default(),
             sync_buf: Default::default(),
             float_buf: Default::default(),
+            byte_buf: Default::default(),
+            blob_buf: Default::default(),
             error: Default::default(),
         }
     }
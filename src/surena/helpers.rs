@@ -0,0 +1,211 @@
+//! Small helpers for common game implementations.
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    error::{Error, ErrorCode, Result},
+    game::{is_random_player, player_id, GameMethods, MoveData},
+    rng::GameRng,
+    MoveDataSync, ValidCString,
+};
+
+/// Push the `winner` into `players` for [`get_results()`].
+///
+/// Nothing is pushed for a draw or an ongoing game (`None`).
+///
+/// [`get_results()`]: crate::game::GameMethods::get_results
+///
+/// # Example
+/// ```
+/// # use mirabel::helpers::single_winner;
+/// let mut players = vec![];
+/// single_winner(&mut players, None);
+/// assert!(players.is_empty());
+/// single_winner(&mut players, Some(2));
+/// assert_eq!(vec![2], players);
+/// ```
+#[inline]
+pub fn single_winner(players: &mut Vec<player_id>, winner: Option<player_id>) {
+    players.extend(winner);
+}
+
+/// Shortcut for two-player zero-sum games with at most one winner.
+///
+/// Implement [`Self::winner()`] and forward
+/// [`get_results()`](crate::game::GameMethods::get_results) to
+/// [`Self::zero_sum_results()`].
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::Result, game::player_id, helpers::TwoPlayerZeroSum};
+/// struct Finished;
+///
+/// impl TwoPlayerZeroSum for Finished {
+///     fn winner(&mut self) -> Result<Option<player_id>> {
+///         Ok(Some(1))
+///     }
+/// }
+///
+/// let mut players = vec![];
+/// Finished.zero_sum_results(&mut players).unwrap();
+/// assert_eq!(vec![1], players);
+/// ```
+pub trait TwoPlayerZeroSum {
+    /// Return the winner or `None` for a draw or an ongoing game.
+    fn winner(&mut self) -> Result<Option<player_id>>;
+
+    /// Fill `players` with the [`Self::winner()`] via [`single_winner()`].
+    fn zero_sum_results(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+        single_winner(players, self.winner()?);
+        Ok(())
+    }
+}
+
+/// Parse and apply a sequence of moves, e.g., for replaying a transcript.
+///
+/// Each move is parsed with [`GameMethods::get_move_data()`], checked with
+/// [`GameMethods::is_legal_move()`], and applied with
+/// [`GameMethods::make_move()`].
+/// This stops at the first unparseable or illegal move and reports its index
+/// in the error message.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::*, game::*, game_init::GameInit, helpers::apply_moves, testing::Countdown};
+/// let mut game = <Countdown as GameMethods>::create(&GameInit::Default).unwrap();
+/// apply_moves(&mut game, &[(1, "3"), (1, "2"), (1, "3")]).unwrap();
+/// assert_eq!(Countdown(2), game);
+///
+/// let error = apply_moves(&mut game, &[(1, "1"), (1, "x")]).unwrap_err();
+/// assert_eq!(ErrorCode::InvalidInput, error.code);
+/// assert!(error.message.to_string().starts_with("move 1: "));
+///
+/// let error = apply_moves(&mut game, &[(1, "2")]).unwrap_err();
+/// assert_eq!(ErrorCode::InvalidMove, error.code);
+/// ```
+pub fn apply_moves<G: GameMethods>(game: &mut G, player_moves: &[(player_id, &str)]) -> Result<()> {
+    for (index, &(player, string)) in player_moves.iter().enumerate() {
+        apply_move(game, player, string).map_err(|e| e.context(&format!("move {index}")))?;
+    }
+    Ok(())
+}
+
+fn apply_move<G: GameMethods>(game: &mut G, player: player_id, string: &str) -> Result<()> {
+    let mov = game.get_move_data(player, string)?;
+    game.is_legal_move(player, MoveDataSync::with_default(mov.to_rust()))?;
+    game.make_move(player, MoveDataSync::with_default(mov.to_rust()))
+}
+
+/// Check whether `player` has any legal moves.
+///
+/// Returns `false` for a terminal or stuck position, where
+/// [`GameMethods::get_concrete_moves()`] succeeds with no moves.
+/// Errors, like querying a player who is not to move, are passed through.
+///
+/// # Example
+/// ```
+/// # use mirabel::{error::*, helpers::has_moves, testing::Countdown};
+/// assert!(has_moves(&mut Countdown(2), 1).unwrap());
+/// assert!(!has_moves(&mut Countdown(0), 1).unwrap());
+/// let error = has_moves(&mut Countdown(2), 2).unwrap_err();
+/// assert_eq!(ErrorCode::InvalidInput, error.code);
+/// ```
+pub fn has_moves<G: GameMethods>(game: &mut G, player: player_id) -> Result<bool> {
+    let mut moves = vec![];
+    game.get_concrete_moves(player, &mut moves)?;
+    Ok(!moves.is_empty())
+}
+
+/// List the concrete moves of `player` as strings, e.g., for a move
+/// selection dropdown.
+///
+/// The moves come from [`GameMethods::get_concrete_moves()`] and are printed
+/// with [`GameMethods::get_move_str()`].
+/// This works for [`MoveCode`](crate::game::MoveCode) and
+/// [`MixedMove`](crate::game::MixedMove) games alike.
+///
+/// # Example
+/// ```
+/// # use mirabel::{helpers::move_strings, testing::Countdown};
+/// assert_eq!(vec!["1", "2", "3"], move_strings(&mut Countdown(5), 1).unwrap());
+/// assert_eq!(vec!["1", "2"], move_strings(&mut Countdown(2), 1).unwrap());
+/// ```
+pub fn move_strings<G: GameMethods>(game: &mut G, player: player_id) -> Result<Vec<String>> {
+    let mut moves = vec![];
+    game.get_concrete_moves(player, &mut moves)?;
+    let mut str_buf = ValidCString::default();
+    moves
+        .iter()
+        .map(|mov| {
+            str_buf.clear();
+            game.get_move_str(
+                player,
+                MoveDataSync::with_default(mov.to_rust()),
+                &mut str_buf,
+            )?;
+            Ok(AsRef::<str>::as_ref(&str_buf).to_owned())
+        })
+        .collect()
+}
+
+/// Run random playouts from `game` until `budget` is used up.
+///
+/// Every playout starts from a fresh clone, so `game` itself is not modified.
+/// Moves are chosen uniformly from [`GameMethods::get_concrete_moves()`] with
+/// a [`GameRng`] seeded by `seed`.
+/// Chance moves of [`PLAYER_RAND`](crate::game::PLAYER_RAND) are drawn with
+/// [`GameMethods::get_random_move()`] instead.
+/// The playout running when the budget elapses is still completed.
+/// Returns the number of completed playouts.
+///
+/// # Example
+/// ```
+/// # use mirabel::{helpers::timed_playouts, testing::Countdown};
+/// # use std::time::Duration;
+/// let mut game = Countdown(10);
+/// let count = timed_playouts(&mut game, 42, Duration::from_millis(10)).unwrap();
+/// assert!(count >= 1);
+/// assert_eq!(Countdown(10), game);
+/// ```
+pub fn timed_playouts<G: GameMethods>(game: &mut G, seed: u64, budget: Duration) -> Result<u64> {
+    let deadline = Instant::now() + budget;
+    let mut rng = GameRng::new(seed);
+    let mut players = vec![];
+    let mut moves = vec![];
+    let mut count = 0;
+    loop {
+        let mut playout = game.clone();
+        random_playout(&mut playout, &mut rng, &mut players, &mut moves)?;
+        count += 1;
+        if Instant::now() >= deadline {
+            return Ok(count);
+        }
+    }
+}
+
+/// Play random moves until no player is left to move.
+fn random_playout<G: GameMethods>(
+    game: &mut G,
+    rng: &mut GameRng,
+    players: &mut Vec<player_id>,
+    moves: &mut Vec<G::Move>,
+) -> Result<()> {
+    loop {
+        players.clear();
+        game.players_to_move(players)?;
+        let Some(&player) = rng.choose(players) else {
+            return Ok(());
+        };
+        if is_random_player(player) {
+            let mov = game.get_random_move(rng)?;
+            game.make_move(player, MoveDataSync::with_default(mov.to_rust()))?;
+            continue;
+        }
+        moves.clear();
+        game.get_concrete_moves(player, moves)?;
+        let mov = rng.choose(moves).ok_or_else(|| {
+            Error::new_static(ErrorCode::InvalidState, "player to move has no moves\0")
+        })?;
+        game.make_move(player, MoveDataSync::with_default(mov.to_rust()))?;
+    }
+}
@@ -1,3 +1,7 @@
 //! Wrapper for _surena_ plugins.
 
+pub mod convert;
 pub mod game;
+pub mod helpers;
+pub mod rng;
+pub mod testing;
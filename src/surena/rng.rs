@@ -0,0 +1,85 @@
+//! Small deterministic random number generator for games.
+
+/// Pseudo random number generator handed to games by the wrapper.
+///
+/// This implements _SplitMix64_, which is fast, has a tiny state, and is good
+/// enough for choosing random moves.
+/// The same seed always yields the same sequence.
+///
+/// # Example
+/// ```
+/// # use mirabel::rng::GameRng;
+/// let mut a = GameRng::new(42);
+/// let mut b = GameRng::new(42);
+/// for _ in 0..8 {
+///     assert_eq!(a.below(6), b.below(6));
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameRng {
+    state: u64,
+}
+
+impl GameRng {
+    /// Create a new generator from a `seed`.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Return the next random [`u64`].
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Return a uniformly distributed number in `0..bound`.
+    ///
+    /// # Panics
+    /// Panics if `bound` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::rng::GameRng;
+    /// let mut rng = GameRng::new(0);
+    /// assert!(rng.below(3) < 3);
+    /// ```
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert_ne!(0, bound, "bound must not be zero");
+        // Reject the incomplete last block to avoid modulo bias.
+        let limit = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < limit {
+                return value % bound;
+            }
+        }
+    }
+
+    /// Return a uniformly distributed [`f32`] in `[0, 1)`.
+    #[inline]
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Choose a random element from `slice` or [`None`] if it is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use mirabel::rng::GameRng;
+    /// let mut rng = GameRng::new(7);
+    /// assert!([1, 2, 3].contains(rng.choose(&[1, 2, 3]).unwrap()));
+    /// assert_eq!(None, rng.choose::<u8>(&[]));
+    /// ```
+    pub fn choose<'l, T>(&mut self, slice: &'l [T]) -> Option<&'l T> {
+        if slice.is_empty() {
+            None
+        } else {
+            slice.get(self.below(slice.len() as u64) as usize)
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Helpers for testing game implementations.
+
+use std::{
+    fmt::{self, Write},
+    ptr::null,
+};
+
+#[cfg(debug_assertions)]
+use std::sync::atomic::Ordering;
+
+use crate::{
+    error::{code_to_result, Error, ErrorCode, Result},
+    game::{
+        move_code, player_id, GameFeatures, GameMethods, MoveCode, MoveData, MoveSink, SimpleGame,
+    },
+    game_init::GameInit,
+    sys, MoveDataSync, ValidCString,
+};
+
+/// Check that printing and parsing `mov` are inverse to each other.
+///
+/// This prints `mov` with [`GameMethods::get_move_str()`], parses the result
+/// with [`GameMethods::get_move_data()`] and compares both moves.
+/// Errors of the game methods are returned.
+///
+/// # Panics
+/// Panics with the printed string if the parsed move differs from `mov`.
+///
+/// # Example
+/// ```
+/// # use mirabel::{game::*, game_init::GameInit, testing::{check_move_roundtrip, Countdown}};
+/// let mut game = <Countdown as GameMethods>::create(&GameInit::Default).unwrap();
+/// for code in 1..=3 {
+///     check_move_roundtrip(&mut game, 1, &MoveCode::from(code)).unwrap();
+/// }
+/// ```
+pub fn check_move_roundtrip<G>(game: &mut G, player: player_id, mov: &G::Move) -> Result<()>
+where
+    G: GameMethods,
+    G::Move: PartialEq + fmt::Debug,
+{
+    let mut str_buf = ValidCString::default();
+    game.get_move_str(
+        player,
+        MoveDataSync::with_default(mov.to_rust()),
+        &mut str_buf,
+    )?;
+    let string: &str = str_buf.as_ref();
+    let parsed = game.get_move_data(player, string)?;
+    assert_eq!(
+        mov, &parsed,
+        "move printed as {string:?} was parsed differently"
+    );
+    Ok(())
+}
+
+/// Check whether `game` is over like the host does.
+///
+/// Unlike [`GameMethods::is_terminal()`], this goes through the exported
+/// `players_to_move` of `game` and checks that it returns no players.
+/// Hence, it also covers the wrapper and the feature flags of `game`.
+///
+/// # Safety
+/// `game` must have been created with valid methods.
+///
+/// # Example
+/// ```
+/// # use mirabel::{game::*, testing::{is_terminal, Countdown}, *};
+/// let methods = create_game_methods::<Countdown>(
+///     Metadata::builder()
+///         .game_name("Countdown")
+///         .variant_name("Standard")
+///         .impl_name("mirabel_rs")
+///         .build(),
+/// );
+/// let mut game = sys::game {
+///     methods: &methods,
+///     ..Default::default()
+/// };
+/// let mut init = sys::game_init {
+///     source_type: sys::GAME_INIT_SOURCE_TYPE_E_GAME_INIT_SOURCE_TYPE_DEFAULT,
+///     ..Default::default()
+/// };
+/// unsafe {
+///     methods.create.unwrap()(&mut game, &mut init);
+///     assert!(!is_terminal(&mut game).unwrap());
+///     methods.import_state.unwrap()(&mut game, b"0\0".as_ptr().cast());
+///     assert!(is_terminal(&mut game).unwrap());
+///     methods.destroy.unwrap()(&mut game);
+/// }
+/// ```
+pub unsafe fn is_terminal(game: &mut sys::game) -> Result<bool> {
+    let players_to_move = (*game.methods)
+        .players_to_move
+        .expect("players_to_move missing");
+    let mut count = 0;
+    let mut players = null();
+    code_to_result(players_to_move(game, &mut count, &mut players))?;
+    Ok(count == 0)
+}
+
+/// Owned string outputs of a game for dumping in test failures.
+///
+/// Created by [`snapshot()`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    /// Output of [`GameMethods::export_state()`].
+    pub state: String,
+    /// Output of [`GameMethods::export_options()`] if
+    /// [`GameFeatures::options`] is enabled.
+    pub options: Option<String>,
+    /// Output of [`GameMethods::print()`] if [`GameFeatures::print`] is
+    /// enabled.
+    pub print: Option<String>,
+    /// Output of [`GameMethods::debug_print()`] if
+    /// [`GameFeatures::debug_print`] is enabled.
+    pub debug: Option<String>,
+}
+
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "state: {}", self.state)?;
+        if let Some(options) = &self.options {
+            writeln!(f, "options: {options}")?;
+        }
+        if let Some(print) = &self.print {
+            write!(f, "{print}")?;
+        }
+        if let Some(debug) = &self.debug {
+            write!(f, "debug: {debug}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Gather the state, options, print, and debug output of `game` for `player`.
+///
+/// Options, print, and debug output are omitted unless enabled in `features`.
+///
+/// # Example
+/// ```
+/// # use mirabel::{game::*, testing::{snapshot, Countdown}};
+/// let mut game = Countdown(7);
+/// let snapshot = snapshot(&mut game, 1, &GameFeatures::default()).unwrap();
+/// assert_eq!("7", snapshot.state);
+/// assert_eq!(None, snapshot.print);
+/// ```
+pub fn snapshot<G: GameMethods>(
+    game: &mut G,
+    player: player_id,
+    features: &GameFeatures,
+) -> Result<Snapshot> {
+    let mut str_buf = ValidCString::default();
+    game.export_state(player, &mut str_buf)?;
+    let state = take(&mut str_buf);
+    let options = if features.options {
+        game.export_options(player, &mut str_buf)?;
+        Some(take(&mut str_buf))
+    } else {
+        None
+    };
+    let print = if features.print {
+        game.print(player, &mut str_buf)?;
+        Some(take(&mut str_buf))
+    } else {
+        None
+    };
+    let debug = if features.debug_print {
+        game.debug_print(&mut str_buf)?;
+        Some(take(&mut str_buf))
+    } else {
+        None
+    };
+    Ok(Snapshot {
+        state,
+        options,
+        print,
+        debug,
+    })
+}
+
+/// Copy the contents of `str_buf` and clear it for reuse.
+fn take(str_buf: &mut ValidCString) -> String {
+    let string: &str = str_buf.as_ref();
+    let string = string.to_owned();
+    str_buf.clear();
+    string
+}
+
+/// Assert that every big [`MixedMove`] buffer has been dropped.
+///
+/// The wrapper counts big move allocations and drops in debug builds.
+/// A nonzero balance points to a leak (positive) or a double free (negative),
+/// e.g., in the move buffer handling of `get_concrete_moves`.
+/// The counter is global, so run this in a test which does not share its
+/// process with other tests holding big moves.
+///
+/// [`MixedMove`]: crate::game::MixedMove
+///
+/// # Panics
+/// Panics with the balance if it is not zero.
+///
+/// # Example
+/// ```
+/// # use mirabel::{game::MixedMove, testing::assert_no_leaked_moves};
+/// let moves: Vec<_> = (0..5u8).map(|i| MixedMove::from(vec![i; 3])).collect();
+/// let code = MixedMove::from(42);
+/// drop(moves);
+/// drop(code);
+/// assert_no_leaked_moves();
+/// ```
+#[cfg(debug_assertions)]
+pub fn assert_no_leaked_moves() {
+    let live = crate::game::LIVE_BIG_MOVES.load(Ordering::Relaxed);
+    assert_eq!(0, live, "{live} big moves are still alive");
+}
+
+/// Shared game for the examples of this crate.
+///
+/// A single player subtracts one to three from a counter until it reaches
+/// zero.
+/// The state is the counter in decimal and defaults to 10.
+/// Moves are printed and parsed as the subtracted number.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Countdown(pub u64);
+
+impl SimpleGame for Countdown {
+    fn create(init_info: &GameInit) -> Result<Self> {
+        let mut game = Self(10);
+        if let GameInit::Standard { state, .. } = init_info {
+            game.import_state(*state)?;
+        }
+        Ok(game)
+    }
+
+    fn player_count(&mut self) -> Result<u8> {
+        Ok(1)
+    }
+
+    fn import_state(&mut self, string: Option<&str>) -> Result<()> {
+        self.0 = string.map_or(Ok(10), str::parse)?;
+        Ok(())
+    }
+
+    fn export_state(&mut self, _: player_id, str_buf: &mut ValidCString) -> Result<()> {
+        write!(str_buf, "{}", self.0)?;
+        Ok(())
+    }
+
+    fn to_move(&mut self) -> Result<Option<player_id>> {
+        Ok((self.0 > 0).then_some(1))
+    }
+
+    fn get_concrete_moves(&mut self, player: player_id, moves: &mut Vec<MoveCode>) -> Result<()> {
+        if SimpleGame::check_to_move(self, player)? {
+            moves.extend_codes(1..=self.0.min(3));
+        }
+        Ok(())
+    }
+
+    fn get_move_data(&mut self, _: player_id, string: &str) -> Result<move_code> {
+        Ok(string.parse()?)
+    }
+
+    fn get_move_str(
+        &mut self,
+        _: player_id,
+        mov: move_code,
+        str_buf: &mut ValidCString,
+    ) -> Result<()> {
+        write!(str_buf, "{mov}")?;
+        Ok(())
+    }
+
+    fn make_move(&mut self, _: player_id, mov: move_code) -> Result<()> {
+        self.0 -= mov;
+        Ok(())
+    }
+
+    fn get_results(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+        if self.0 == 0 {
+            players.push(1);
+        }
+        Ok(())
+    }
+
+    fn is_legal_move(&mut self, _: player_id, mov: move_code) -> Result<()> {
+        if (1..=self.0.min(3)).contains(&mov) {
+            Ok(())
+        } else {
+            Err(Error::new_static(ErrorCode::InvalidMove, "illegal move\0"))
+        }
+    }
+}